@@ -572,6 +572,39 @@ fn rodeo_fxhash_threaded(c: &mut Criterion) {
     group.finish();
 }
 
+fn rodeo_resolve_heavy(c: &mut Criterion) {
+    use std::collections::hash_map::RandomState;
+
+    /// The number of times every key is resolved for each string that's looked up
+    const RESOLVES_PER_GET: usize = 8;
+
+    let mut group = c.benchmark_group("ThreadedRodeo Resolve Heavy (std)");
+    group.throughput(Throughput::Bytes(INPUT.len() as u64));
+
+    for &threads in &[1, NUM_THREADS] {
+        group.bench_function(format!("resolve {} Thread", threads), |b| {
+            b.iter_custom(|iters| {
+                run_threaded_filled(
+                    |rodeo, keys| {
+                        for (&line, key) in setup::bench_lines().iter().zip(keys) {
+                            black_box(rodeo.get(line));
+
+                            for _ in 0..RESOLVES_PER_GET {
+                                black_box(rodeo.resolve(black_box(key)));
+                            }
+                        }
+                    },
+                    threads,
+                    iters,
+                    RandomState::new(),
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     rodeo_std,
@@ -579,6 +612,7 @@ criterion_group!(
     rodeo_fxhash,
     rodeo_std_threaded,
     rodeo_ahash_threaded,
-    rodeo_fxhash_threaded
+    rodeo_fxhash_threaded,
+    rodeo_resolve_heavy
 );
 criterion_main!(benches);
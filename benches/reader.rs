@@ -1,7 +1,11 @@
 mod setup;
 
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
-use setup::{ReaderEmptySetup, ReaderFilledSetup, INPUT, NUM_THREADS};
+#[cfg(feature = "multi-threaded")]
+use setup::ThreadedRodeoFilledSetup;
+use setup::{
+    bench_lines, ReaderEmptySetup, ReaderFilledSetup, RodeoFilledSetup, INPUT, NUM_THREADS,
+};
 
 fn reader_std(c: &mut Criterion) {
     use std::collections::hash_map::RandomState;
@@ -432,6 +436,84 @@ fn reader_fxhash_threaded(c: &mut Criterion) {
     group.finish();
 }
 
+fn reader_conversions(c: &mut Criterion) {
+    use std::collections::hash_map::RandomState;
+
+    let mut group = c.benchmark_group("RodeoReader Conversions (std)");
+    group.throughput(Throughput::Bytes(INPUT.len() as u64));
+
+    let setup = RodeoFilledSetup::new(RandomState::default());
+    group.bench_function("Rodeo::into_reader", |b| {
+        b.iter_batched(
+            || setup.filled_rodeo().clone(),
+            |rodeo| black_box(rodeo.into_reader()),
+            BatchSize::LargeInput,
+        )
+    });
+
+    #[cfg(feature = "multi-threaded")]
+    group.bench_function("ThreadedRodeo::into_reader", |b| {
+        b.iter_batched(
+            || ThreadedRodeoFilledSetup::new(RandomState::default()).into_inner(),
+            |rodeo| black_box(rodeo.into_reader()),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn reader_misses(c: &mut Criterion) {
+    use lasso::{Rodeo, Spur};
+    use std::collections::hash_map::RandomState;
+
+    let mut group = c.benchmark_group("RodeoReader Misses (std)");
+    group.throughput(Throughput::Bytes(INPUT.len() as u64));
+
+    // Every line is interned, but only every twentieth lookup is of an interned line
+    let mut rodeo: Rodeo<str, Spur, _> = Rodeo::with_hasher(RandomState::default());
+    for &line in bench_lines() {
+        rodeo.get_or_intern(line);
+    }
+
+    let lines = bench_lines()
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if i % 20 == 0 {
+                line.to_string()
+            } else {
+                format!("{}{}", line, i)
+            }
+        })
+        .collect::<Vec<String>>();
+
+    let reader = rodeo.clone().into_reader();
+    group.bench_function("get (mostly misses)", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(reader.get(line));
+            }
+        })
+    });
+
+    for &rate in &[0.01, 0.001] {
+        let reader = rodeo.clone().into_reader_with_bloom_filter(rate);
+        group.bench_function(
+            format!("get (mostly misses, bloom filter at {})", rate),
+            |b| {
+                b.iter(|| {
+                    for line in &lines {
+                        black_box(reader.get(line));
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     reader_std,
@@ -439,6 +521,8 @@ criterion_group!(
     reader_fxhash,
     reader_std_threaded,
     reader_ahash_threaded,
-    reader_fxhash_threaded
+    reader_fxhash_threaded,
+    reader_conversions,
+    reader_misses
 );
 criterion_main!(benches);
@@ -1,7 +1,9 @@
 mod setup;
 
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
-use setup::{ResolverFilledSetup, INPUT, NUM_THREADS};
+#[cfg(feature = "multi-threaded")]
+use setup::ThreadedRodeoFilledSetup;
+use setup::{ReaderFilledSetup, ResolverFilledSetup, RodeoFilledSetup, INPUT, NUM_THREADS};
 
 fn resolver(c: &mut Criterion) {
     let mut group = c.benchmark_group("RodeoResolver 1 Thread");
@@ -98,5 +100,77 @@ fn resolver_threaded(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, resolver, resolver_threaded);
+fn resolver_conversions(c: &mut Criterion) {
+    use std::collections::hash_map::RandomState;
+
+    let mut group = c.benchmark_group("RodeoResolver Conversions");
+    group.throughput(Throughput::Bytes(INPUT.len() as u64));
+
+    let setup = RodeoFilledSetup::new(RandomState::default());
+    group.bench_function("Rodeo::into_resolver", |b| {
+        b.iter_batched(
+            || setup.filled_rodeo().clone(),
+            |rodeo| black_box(rodeo.into_resolver()),
+            BatchSize::LargeInput,
+        )
+    });
+
+    let setup = ReaderFilledSetup::new(RandomState::default());
+    group.bench_function("RodeoReader::into_resolver", |b| {
+        b.iter_batched(
+            || setup.filled_rodeo().clone(),
+            |reader| black_box(reader.into_resolver()),
+            BatchSize::LargeInput,
+        )
+    });
+
+    #[cfg(feature = "multi-threaded")]
+    group.bench_function("ThreadedRodeo::into_resolver", |b| {
+        b.iter_batched(
+            || ThreadedRodeoFilledSetup::new(RandomState::default()).into_inner(),
+            |rodeo| black_box(rodeo.into_resolver()),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn resolver_front_coded(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FrontCodedResolver 1 Thread");
+    group.throughput(Throughput::Bytes(INPUT.len() as u64));
+
+    let setup = ResolverFilledSetup::new();
+    for &block_size in &[4, 16, 64] {
+        let resolver = setup.filled_rodeo().clone().into_front_coded(block_size);
+
+        group.bench_function(format!("resolve (block size {})", block_size), |b| {
+            b.iter(|| {
+                for key in setup.keys() {
+                    black_box(resolver.resolve(key));
+                }
+            })
+        });
+
+        group.bench_function(format!("resolve_into (block size {})", block_size), |b| {
+            let mut buf = String::new();
+
+            b.iter(|| {
+                for key in setup.keys() {
+                    black_box(resolver.resolve_into(key, &mut buf));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    resolver,
+    resolver_threaded,
+    resolver_conversions,
+    resolver_front_coded
+);
 criterion_main!(benches);
@@ -1,7 +1,9 @@
 #![allow(dead_code)]
 
 use core::hash::BuildHasher;
-use lasso::{Rodeo, RodeoReader, RodeoResolver, Spur, ThreadedRodeo};
+#[cfg(feature = "multi-threaded")]
+use lasso::ThreadedRodeo;
+use lasso::{Rodeo, RodeoReader, RodeoResolver, Spur};
 use std::{
     sync::{atomic::AtomicBool, Arc, Barrier},
     thread,
@@ -10,7 +12,7 @@ use string_interner::{StringInterner, Sym};
 
 pub const NUM_THREADS: usize = 24;
 
-pub static INPUT: &'static str = include_str!("input.txt");
+pub static INPUT: &str = include_str!("input.txt");
 
 lazy_static::lazy_static! {
     pub static ref INPUT_LINES: Vec<&'static str> =
@@ -36,7 +38,7 @@ impl<S: BuildHasher + Clone> RodeoEmptySetup<S> {
         }
     }
 
-    pub fn empty_rodeo(&self) -> Rodeo<Spur, S> {
+    pub fn empty_rodeo(&self) -> Rodeo<str, Spur, S> {
         Rodeo::with_capacity_and_hasher(self.lines.len(), self.build_hasher.clone())
     }
 
@@ -47,7 +49,7 @@ impl<S: BuildHasher + Clone> RodeoEmptySetup<S> {
 
 pub struct RodeoFilledSetup<S: BuildHasher + Clone> {
     lines: &'static [&'static str],
-    rodeo: Rodeo<Spur, S>,
+    rodeo: Rodeo<str, Spur, S>,
     keys: Vec<Spur>,
 }
 
@@ -56,7 +58,7 @@ impl<S: BuildHasher + Clone> RodeoFilledSetup<S> {
         let lines = bench_lines();
         let mut rodeo = Rodeo::with_capacity_and_hasher(lines.len(), hash_builder);
         let keys = lines
-            .into_iter()
+            .iter()
             .map(|&line| rodeo.get_or_intern(line))
             .collect::<Vec<_>>();
 
@@ -67,11 +69,11 @@ impl<S: BuildHasher + Clone> RodeoFilledSetup<S> {
         self.lines
     }
 
-    pub fn filled_rodeo(&self) -> &Rodeo<Spur, S> {
+    pub fn filled_rodeo(&self) -> &Rodeo<str, Spur, S> {
         &self.rodeo
     }
 
-    pub fn filled_rodeo_mut(&mut self) -> &mut Rodeo<Spur, S> {
+    pub fn filled_rodeo_mut(&mut self) -> &mut Rodeo<str, Spur, S> {
         &mut self.rodeo
     }
 
@@ -95,7 +97,7 @@ impl<S: BuildHasher + Clone> ReaderEmptySetup<S> {
         }
     }
 
-    pub fn empty_rodeo(&self) -> RodeoReader<Spur, S> {
+    pub fn empty_rodeo(&self) -> RodeoReader<str, Spur, S> {
         Rodeo::with_capacity_and_hasher(self.lines.len(), self.build_hasher.clone()).into_reader()
     }
 
@@ -106,7 +108,7 @@ impl<S: BuildHasher + Clone> ReaderEmptySetup<S> {
 
 pub struct ReaderFilledSetup<S: BuildHasher + Clone> {
     lines: &'static [&'static str],
-    reader: RodeoReader<Spur, S>,
+    reader: RodeoReader<str, Spur, S>,
     keys: Vec<Spur>,
 }
 
@@ -115,7 +117,7 @@ impl<S: BuildHasher + Clone> ReaderFilledSetup<S> {
         let lines = bench_lines();
         let mut rodeo = Rodeo::with_capacity_and_hasher(lines.len(), hash_builder);
         let keys = lines
-            .into_iter()
+            .iter()
             .map(|&line| rodeo.get_or_intern(line))
             .collect::<Vec<_>>();
         let reader = rodeo.into_reader();
@@ -127,7 +129,7 @@ impl<S: BuildHasher + Clone> ReaderFilledSetup<S> {
         }
     }
 
-    pub fn into_inner(self) -> RodeoReader<Spur, S> {
+    pub fn into_inner(self) -> RodeoReader<str, Spur, S> {
         self.reader
     }
 
@@ -135,11 +137,11 @@ impl<S: BuildHasher + Clone> ReaderFilledSetup<S> {
         self.lines
     }
 
-    pub fn filled_rodeo(&self) -> &RodeoReader<Spur, S> {
+    pub fn filled_rodeo(&self) -> &RodeoReader<str, Spur, S> {
         &self.reader
     }
 
-    pub fn filled_rodeo_mut(&mut self) -> &mut RodeoReader<Spur, S> {
+    pub fn filled_rodeo_mut(&mut self) -> &mut RodeoReader<str, Spur, S> {
         &mut self.reader
     }
 
@@ -155,7 +157,7 @@ pub fn run_reader_filled<F, S>(
     iters: u64,
 ) -> std::time::Duration
 where
-    F: FnOnce(&RodeoReader<Spur, S>, &[Spur]) + Send + 'static + Clone + Copy,
+    F: FnOnce(&RodeoReader<str, Spur, S>, &[Spur]) + Send + 'static + Clone + Copy,
     S: 'static + BuildHasher + Clone + Send + Sync,
 {
     use std::sync::atomic::Ordering;
@@ -171,12 +173,11 @@ where
     for _ in 0..num_threads - 1 {
         let barrier = barrier.clone();
         let reader = reader.clone();
-        let func = func.clone();
         let running = running.clone();
         let keys = keys.clone();
 
         threads.push(thread::spawn(move || {
-            let reader: &RodeoReader<Spur, S> = &*reader;
+            let reader: &RodeoReader<str, Spur, S> = &reader;
             barrier.wait();
             while running.load(Ordering::Relaxed) {
                 func(reader, &keys)
@@ -184,7 +185,7 @@ where
         }));
     }
 
-    let reader: &RodeoReader<Spur, S> = &*reader;
+    let reader: &RodeoReader<str, Spur, S> = &reader;
     barrier.wait();
     let start = Instant::now();
     for _ in 0..iters {
@@ -200,7 +201,7 @@ where
 
 pub fn run_resolver_filled<F>(func: F, num_threads: usize, iters: u64) -> std::time::Duration
 where
-    F: FnOnce(&RodeoResolver<Spur>, &[Spur]) + Send + 'static + Clone + Copy,
+    F: FnOnce(&RodeoResolver<str, Spur>, &[Spur]) + Send + 'static + Clone + Copy,
 {
     use std::sync::atomic::Ordering;
     use std::time::Instant;
@@ -215,12 +216,11 @@ where
     for _ in 0..num_threads - 1 {
         let barrier = barrier.clone();
         let reader = reader.clone();
-        let func = func.clone();
         let running = running.clone();
         let keys = keys.clone();
 
         threads.push(thread::spawn(move || {
-            let reader: &RodeoResolver<Spur> = &*reader;
+            let reader: &RodeoResolver<str, Spur> = &reader;
             barrier.wait();
             while running.load(Ordering::Relaxed) {
                 func(reader, &keys)
@@ -228,7 +228,7 @@ where
         }));
     }
 
-    let reader: &RodeoResolver<Spur> = &*reader;
+    let reader: &RodeoResolver<str, Spur> = &reader;
     barrier.wait();
     let start = Instant::now();
     for _ in 0..iters {
@@ -244,10 +244,16 @@ where
 
 pub struct ResolverFilledSetup {
     lines: &'static [&'static str],
-    resolver: RodeoResolver<Spur>,
+    resolver: RodeoResolver<str, Spur>,
     keys: Vec<Spur>,
 }
 
+impl Default for ResolverFilledSetup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ResolverFilledSetup {
     pub fn new() -> Self {
         let lines = bench_lines();
@@ -256,7 +262,7 @@ impl ResolverFilledSetup {
             std::collections::hash_map::RandomState::new(),
         );
         let keys = lines
-            .into_iter()
+            .iter()
             .map(|&line| rodeo.get_or_intern(line))
             .collect::<Vec<_>>();
         let resolver = rodeo.into_resolver();
@@ -268,7 +274,7 @@ impl ResolverFilledSetup {
         }
     }
 
-    pub fn into_inner(self) -> RodeoResolver<Spur> {
+    pub fn into_inner(self) -> RodeoResolver<str, Spur> {
         self.resolver
     }
 
@@ -276,11 +282,11 @@ impl ResolverFilledSetup {
         self.lines
     }
 
-    pub fn filled_rodeo(&self) -> &RodeoResolver<Spur> {
+    pub fn filled_rodeo(&self) -> &RodeoResolver<str, Spur> {
         &self.resolver
     }
 
-    pub fn filled_rodeo_mut(&mut self) -> &mut RodeoResolver<Spur> {
+    pub fn filled_rodeo_mut(&mut self) -> &mut RodeoResolver<str, Spur> {
         &mut self.resolver
     }
 
@@ -289,25 +295,27 @@ impl ResolverFilledSetup {
     }
 }
 
+#[cfg(feature = "multi-threaded")]
 pub struct ThreadedRodeoFilledSetup<S: BuildHasher + Clone> {
     lines: &'static [&'static str],
-    rodeo: ThreadedRodeo<Spur, S>,
+    rodeo: ThreadedRodeo<str, Spur, S>,
     keys: Vec<Spur>,
 }
 
+#[cfg(feature = "multi-threaded")]
 impl<S: BuildHasher + Clone> ThreadedRodeoFilledSetup<S> {
     pub fn new(hash_builder: S) -> Self {
         let lines = bench_lines();
         let rodeo = ThreadedRodeo::with_capacity_and_hasher(lines.len(), hash_builder);
         let keys = lines
-            .into_iter()
+            .iter()
             .map(|&line| rodeo.get_or_intern(line))
             .collect::<Vec<_>>();
 
         Self { lines, rodeo, keys }
     }
 
-    pub fn into_inner(self) -> ThreadedRodeo<Spur, S> {
+    pub fn into_inner(self) -> ThreadedRodeo<str, Spur, S> {
         self.rodeo
     }
 
@@ -315,11 +323,11 @@ impl<S: BuildHasher + Clone> ThreadedRodeoFilledSetup<S> {
         self.lines
     }
 
-    pub fn filled_rodeo(&self) -> &ThreadedRodeo<Spur, S> {
+    pub fn filled_rodeo(&self) -> &ThreadedRodeo<str, Spur, S> {
         &self.rodeo
     }
 
-    pub fn filled_rodeo_mut(&mut self) -> &mut ThreadedRodeo<Spur, S> {
+    pub fn filled_rodeo_mut(&mut self) -> &mut ThreadedRodeo<str, Spur, S> {
         &mut self.rodeo
     }
 
@@ -328,11 +336,13 @@ impl<S: BuildHasher + Clone> ThreadedRodeoFilledSetup<S> {
     }
 }
 
+#[cfg(feature = "multi-threaded")]
 pub struct ThreadedRodeoEmptySetup<S: BuildHasher + Clone> {
     lines: &'static [&'static str],
     build_hasher: S,
 }
 
+#[cfg(feature = "multi-threaded")]
 impl<S: BuildHasher + Clone> ThreadedRodeoEmptySetup<S> {
     pub fn new(build_hasher: S) -> Self {
         let lines = bench_lines();
@@ -343,7 +353,7 @@ impl<S: BuildHasher + Clone> ThreadedRodeoEmptySetup<S> {
         }
     }
 
-    pub fn empty_rodeo(&self) -> ThreadedRodeo<Spur, S> {
+    pub fn empty_rodeo(&self) -> ThreadedRodeo<str, Spur, S> {
         ThreadedRodeo::with_capacity_and_hasher(self.lines.len(), self.build_hasher.clone())
     }
 
@@ -352,6 +362,7 @@ impl<S: BuildHasher + Clone> ThreadedRodeoEmptySetup<S> {
     }
 }
 
+#[cfg(feature = "multi-threaded")]
 pub fn run_threaded_filled<F, S>(
     func: F,
     num_threads: usize,
@@ -359,7 +370,7 @@ pub fn run_threaded_filled<F, S>(
     hash: S,
 ) -> std::time::Duration
 where
-    F: FnOnce(&ThreadedRodeo<Spur, S>, &[Spur]) + Send + 'static + Clone + Copy,
+    F: FnOnce(&ThreadedRodeo<str, Spur, S>, &[Spur]) + Send + 'static + Clone + Copy,
     S: BuildHasher + Clone + Send + Sync + 'static,
 {
     use std::sync::atomic::Ordering;
@@ -375,12 +386,11 @@ where
     for _ in 0..num_threads - 1 {
         let barrier = barrier.clone();
         let reader = Arc::clone(&reader);
-        let func = func.clone();
         let running = running.clone();
         let keys = keys.clone();
 
         threads.push(thread::spawn(move || {
-            let reader: &ThreadedRodeo<Spur, S> = &*reader;
+            let reader: &ThreadedRodeo<str, Spur, S> = &reader;
             barrier.wait();
             while running.load(Ordering::Relaxed) {
                 func(reader, &keys)
@@ -388,7 +398,7 @@ where
         }));
     }
 
-    let reader: &ThreadedRodeo<Spur, S> = &*reader;
+    let reader: &ThreadedRodeo<str, Spur, S> = &reader;
     barrier.wait();
     let start = Instant::now();
     for _ in 0..iters {
@@ -438,7 +448,7 @@ impl<S: BuildHasher + Clone> StringInternerFilledSetup<S> {
         let mut string_interner =
             StringInterner::with_capacity_and_hasher(lines.len(), hash_builder);
         let keys = lines
-            .into_iter()
+            .iter()
             .map(|&line| string_interner.get_or_intern(line))
             .collect::<Vec<_>>();
 
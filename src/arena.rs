@@ -14,7 +14,6 @@ use core::{
     cmp, fmt, mem,
     num::NonZeroUsize,
     ptr::{self, NonNull},
-    slice,
 };
 
 /// An arena allocator that dynamically grows in size when needed, allocating memory in large chunks
@@ -113,14 +112,16 @@ impl<T: Sized + Clone> Drop for Bucket<T> {
                 ptr::drop_in_place(items.add(i));
             }
 
-            // Deallocate all memory that the bucket allocated
-            dealloc(
-                items as *mut u8,
-                Layout::from_size_align_unchecked(
-                    mem::size_of::<T>() * self.capacity.get(),
-                    mem::align_of::<T>(),
-                ),
-            );
+            // Deallocate all memory that the bucket allocated, zsts never allocated anything
+            if mem::size_of::<T>() != 0 {
+                dealloc(
+                    items as *mut u8,
+                    Layout::from_size_align_unchecked(
+                        mem::size_of::<T>() * self.capacity.get(),
+                        mem::align_of::<T>(),
+                    ),
+                );
+            }
         }
     }
 }
@@ -129,6 +130,15 @@ impl<T: Sized + Clone> Bucket<T> {
     /// Allocates a bucket with space for `capacity` items
     #[inline]
     pub(crate) fn with_capacity(capacity: NonZeroUsize) -> Self {
+        // Zero-sized allocations are UB, so zsts get a dangling pointer instead
+        if mem::size_of::<T>() == 0 {
+            return Self {
+                index: 0,
+                capacity,
+                items: NonNull::dangling(),
+            };
+        }
+
         unsafe {
             let layout = Layout::from_size_align_unchecked(
                 mem::size_of::<T>() * capacity.get(),
@@ -170,8 +180,12 @@ impl<T: Sized + Clone> Bucket<T> {
         debug_assert!(slice.len() <= self.capacity.get() - self.index);
 
         let ptr = self.items.as_ptr().add(self.index);
-        let target = slice::from_raw_parts_mut(ptr, slice.len());
-        target.clone_from_slice(slice);
+
+        // The target memory is uninitialized, so the items must be written instead of
+        // assigned to avoid dropping garbage values
+        for (i, item) in slice.iter().enumerate() {
+            ptr::write(ptr.add(i), item.clone());
+        }
         self.index += slice.len();
 
         // Safety: The caller promises to forget the reference before the arena is dropped
@@ -186,6 +200,12 @@ unsafe impl<T: Sync + Clone> Sync for Bucket<T> {}
 mod tests {
     use super::*;
 
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::string::ToString;
+        }
+    }
+
     #[test]
     fn string() {
         let mut arena = Arena::new();
@@ -209,4 +229,42 @@ mod tests {
             assert_eq!(zst2, b"");
         }
     }
+
+    #[test]
+    fn zst() {
+        let mut arena = Arena::new();
+
+        unsafe {
+            let units = arena.store_slice(&[(), (), ()]);
+            let unit = arena.store_slice(&[()]);
+
+            assert_eq!(units, &[(), (), ()]);
+            assert_eq!(unit, &[()]);
+        }
+    }
+
+    #[test]
+    fn drop_items() {
+        let mut arena = Arena::new();
+
+        unsafe {
+            let strings = arena.store_slice(&["a".to_string(), "b".to_string()]);
+            let string = arena.store_slice(&["c".to_string()]);
+
+            assert_eq!(strings, &["a".to_string(), "b".to_string()]);
+            assert_eq!(string, &["c".to_string()]);
+        }
+    }
+
+    #[test]
+    fn items_without_eq() {
+        let mut arena = Arena::new();
+
+        unsafe {
+            let floats = arena.store_slice(&[0.5, f32::NAN]);
+
+            assert_eq!(floats[0], 0.5);
+            assert!(floats[1].is_nan());
+        }
+    }
 }
@@ -0,0 +1,206 @@
+use core::{f64::consts::LOG2_E, mem};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::{vec, vec::Vec};
+    }
+}
+
+/// The number of words in a block, making each block the size of a cache line
+const WORDS_PER_BLOCK: usize = 8;
+const BITS_PER_BLOCK: u64 = (WORDS_PER_BLOCK * 64) as u64;
+
+/// How many more bits a blocked filter needs than an unblocked one for each hash, measured so that
+/// filters stay within their false positive rate down to rates of 0.01%
+const BLOCK_OVERHEAD: f64 = 0.012;
+
+/// A blocked bloom filter over the hashes of interned strings, which can tell that a string isn't
+/// interned without probing the map it's in front of
+///
+/// Every bit a string sets is derived from the one hash the map already uses for it, so building the
+/// filter from cached hashes and checking it for a string that was hashed for the map is free of rehashing.
+/// All of a string's bits are within a single cache line sized block, so a check touches one cache line
+#[derive(Debug, Clone)]
+pub(crate) struct BloomFilter {
+    blocks: Vec<[u64; WORDS_PER_BLOCK]>,
+    /// The number of bits set for each string
+    num_hashes: u32,
+    false_positive_rate: f64,
+}
+
+impl BloomFilter {
+    /// Creates a filter holding the given hashes that's sized so at most `false_positive_rate` of the
+    /// strings that weren't given are reported as possibly present
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` isn't between zero and one exclusive
+    ///
+    #[inline]
+    pub(crate) fn new(hashes: &[u64], false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "The false positive rate of a bloom filter must be between zero and one exclusive",
+        );
+
+        // The ideal number of bits to set per string is `log2(1 / rate)`, which is rounded up so the filter
+        // is never looser than requested
+        let mut num_hashes = 1;
+        let mut rate = 0.5;
+        while rate > false_positive_rate && num_hashes < 32 {
+            num_hashes += 1;
+            rate /= 2.0;
+        }
+
+        // With the ideal number of hashes an unblocked filter needs `num_hashes / ln(2)` bits per string, and
+        // blocks need some more since some of them end up holding more strings than others
+        let bits_per_string =
+            num_hashes as f64 * LOG2_E * (1.0 + num_hashes as f64 * BLOCK_OVERHEAD);
+        let num_blocks =
+            (hashes.len() as f64 * bits_per_string / BITS_PER_BLOCK as f64) as usize + 1;
+
+        let mut filter = Self {
+            blocks: vec![[0; WORDS_PER_BLOCK]; num_blocks],
+            num_hashes,
+            false_positive_rate,
+        };
+
+        for &hash in hashes {
+            let block = filter.block_of(hash);
+            for bit in bits(hash, num_hashes) {
+                filter.blocks[block][(bit / 64) as usize] |= 1 << (bit % 64);
+            }
+        }
+
+        filter
+    }
+
+    /// Returns `false` if the string with the given hash is definitely not in the filter
+    #[inline]
+    pub(crate) fn may_contain(&self, hash: u64) -> bool {
+        let block = &self.blocks[self.block_of(hash)];
+
+        // Every bit is checked instead of stopping at the first unset one, since the unset bit of a missing
+        // string is at a random position and branching on each bit mispredicts far more often than not
+        bits(hash, self.num_hashes).fold(true, |found, bit| {
+            found & (block[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+        })
+    }
+
+    /// Gets the false positive rate the filter was created with
+    #[inline]
+    pub(crate) fn false_positive_rate(&self) -> f64 {
+        self.false_positive_rate
+    }
+
+    /// Returns the number of bytes used by the filter
+    #[inline]
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        self.blocks.capacity() * mem::size_of::<[u64; WORDS_PER_BLOCK]>()
+    }
+
+    /// Gets the block a hash's bits are in by scaling the hash down with a multiply instead of a division
+    #[inline]
+    fn block_of(&self, hash: u64) -> usize {
+        ((u128::from(hash) * self.blocks.len() as u128) >> 64) as usize
+    }
+}
+
+/// Gets the bits of a hash within its block, taking each from the top bits of the hash multiplied by
+/// another power of a large odd constant so that they're as independent as possible
+#[inline]
+fn bits(hash: u64, num_hashes: u32) -> impl Iterator<Item = u64> {
+    // The block was chosen with the high bits of the hash, so the bits within it are taken from a
+    // rotation that puts the low bits on top
+    let hash = hash.rotate_left(32);
+
+    // Every multiplication is independent of the others instead of a chain, so they can all happen at once
+    MULTIPLIERS[..num_hashes as usize]
+        .iter()
+        .map(move |&multiplier| {
+            hash.wrapping_mul(multiplier) >> (64 - BITS_PER_BLOCK.trailing_zeros())
+        })
+}
+
+/// The first 32 powers of the golden ratio constant, one for each hash a filter can use
+const MULTIPLIERS: [u64; 32] = {
+    let mut multipliers = [0; 32];
+    let mut power = 0x9E37_79B9_7F4A_7C15_u64;
+
+    let mut i = 0;
+    while i < multipliers.len() {
+        multipliers[i] = power;
+        power = power.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        i += 1;
+    }
+
+    multipliers
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small xorshift generator standing in for hashes of distinct strings
+    fn hashes(seed: u64, count: usize) -> Vec<u64> {
+        let mut state = seed;
+
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_false_negatives() {
+        let present = hashes(0x2545_F491_4F6C_DD1D, 10_000);
+        let filter = BloomFilter::new(&present, 0.01);
+
+        assert!(present.iter().all(|&hash| filter.may_contain(hash)));
+    }
+
+    #[test]
+    fn false_positive_rate() {
+        let present = hashes(0x2545_F491_4F6C_DD1D, 10_000);
+        let absent = hashes(0x9E37_79B9_7F4A_7C15, 100_000);
+
+        for &rate in &[0.1, 0.01, 0.001] {
+            let filter = BloomFilter::new(&present, rate);
+            let false_positives = absent
+                .iter()
+                .filter(|&&hash| filter.may_contain(hash))
+                .count();
+
+            assert_eq!(rate, filter.false_positive_rate());
+            assert!((false_positives as f64 / absent.len() as f64) < rate * 1.5);
+        }
+    }
+
+    #[test]
+    fn smaller_rates_use_more_bits() {
+        let present = hashes(1, 1000);
+
+        assert!(
+            BloomFilter::new(&present, 0.1).size_in_bytes()
+                < BloomFilter::new(&present, 0.001).size_in_bytes()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let filter = BloomFilter::new(&[], 0.01);
+
+        assert!(!filter.may_contain(0));
+        assert!(!filter.may_contain(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_rate() {
+        BloomFilter::new(&[], 1.0);
+    }
+}
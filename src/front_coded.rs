@@ -0,0 +1,505 @@
+use crate::{
+    key::{Key, Spur},
+    table::StringTable,
+};
+
+use core::{marker::PhantomData, mem, num::NonZeroUsize, str};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::{borrow::Cow, string::String, vec::Vec};
+    } else {
+        use std::borrow::Cow;
+    }
+}
+
+/// A compressed, read-only view of interned strings that only allows key to string resolution,
+/// storing its strings in blocks with front coding
+///
+/// The first string of every block is stored whole, and every string after it only stores how many
+/// bytes it shares with the string before it followed by the bytes that differ. Strings are coded
+/// in key order, so the more consecutive keys share prefixes the smaller the resolver gets. Converting
+/// a [`SortedReader`] gives the best compression, since neighbouring keys of sorted strings share the
+/// longest prefixes possible
+///
+/// Compared to a [`RodeoResolver`], which takes 16 bytes per string on top of the strings themselves
+/// and resolves a key with a single lookup, a `FrontCodedResolver` takes one or two bytes per string
+/// on top of the bytes that aren't shared with their neighbours. In exchange, resolving a key has to
+/// decode every string before it in its block and may have to allocate, so larger blocks compress
+/// better but resolve slower. Use [`size_in_bytes`] to see how much memory the resolver takes
+///
+/// Created with `into_front_coded` on [`RodeoResolver`] or [`SortedReader`]
+///
+/// [`RodeoResolver`]: crate::RodeoResolver
+/// [`SortedReader`]: crate::SortedReader
+/// [`size_in_bytes`]: FrontCodedResolver::size_in_bytes
+#[derive(Debug, Clone)]
+pub struct FrontCodedResolver<K = Spur>
+where
+    K: Key,
+{
+    /// The coded strings, where every block starts with the length and bytes of its first string
+    /// and every other string is its shared prefix length, suffix length and suffix bytes, all lengths
+    /// being LEB128 varints
+    data: Vec<u8>,
+    /// The offset of every block in `data`
+    blocks: Vec<usize>,
+    block_size: NonZeroUsize,
+    len: usize,
+    __key: PhantomData<K>,
+}
+
+impl<K> FrontCodedResolver<K>
+where
+    K: Key,
+{
+    /// Codes the given strings, where each string's index is its key
+    #[inline]
+    pub(crate) fn new(strings: &StringTable<str>, block_size: NonZeroUsize) -> Self {
+        let mut data = Vec::new();
+        let mut blocks = Vec::with_capacity(strings.len() / block_size.get() + 1);
+
+        let mut remaining = strings.iter();
+        while remaining.len() != 0 {
+            blocks.push(data.len());
+            let mut block = remaining.by_ref().take(block_size.get());
+
+            let mut previous = block.next().unwrap_or_else(|| unreachable!()).as_bytes();
+            write_varint(&mut data, previous.len());
+            data.extend_from_slice(previous);
+
+            for string in block {
+                let string = string.as_bytes();
+                let shared = previous
+                    .iter()
+                    .zip(string)
+                    .take_while(|(a, b)| a == b)
+                    .count();
+
+                write_varint(&mut data, shared);
+                write_varint(&mut data, string.len() - shared);
+                data.extend_from_slice(&string[shared..]);
+
+                previous = string;
+            }
+        }
+
+        data.shrink_to_fit();
+
+        Self {
+            data,
+            blocks,
+            block_size,
+            len: strings.len(),
+            __key: PhantomData,
+        }
+    }
+
+    /// Resolves a string by its key, borrowing it when it's stored whole and allocating it otherwise,
+    /// including for keys that hold their string within themselves. Only keys made by the current resolver
+    /// or the creator of the current resolver may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("crate::module::Type::method");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert_eq!("crate::module::Type::method", resolver.resolve(&key));
+    /// ```
+    ///
+    #[inline]
+    pub fn resolve(&self, key: &K) -> Cow<'_, str> {
+        self.try_resolve(key).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key, returning `None` if the key is out of bounds. Only keys made by
+    /// the current resolver or the creator of the current resolver may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Key, Rodeo, Spur};
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("crate::module::Type::method");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert_eq!(Some("crate::module::Type::method".into()), resolver.try_resolve(&key));
+    /// assert_eq!(None, resolver.try_resolve(&Spur::try_from_usize(10).unwrap()));
+    /// ```
+    ///
+    #[inline]
+    pub fn try_resolve(&self, key: &K) -> Option<Cow<'_, str>> {
+        if let Some(string) = key.as_inline::<str>() {
+            return Some(Cow::Owned(string.into()));
+        }
+
+        let idx = self.index_of(key)?;
+        let mut buf = Vec::new();
+
+        // Safety: Whole strings and the strings put together in `buf` were all valid strings when coded
+        let string = match self.decode(idx, &mut buf) {
+            Some(whole) => Cow::Borrowed(unsafe { str::from_utf8_unchecked(whole) }),
+            None => Cow::Owned(unsafe { String::from_utf8_unchecked(buf) }),
+        };
+
+        Some(string)
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents. Reusing the
+    /// buffer avoids allocating for every string. Only keys made by the current resolver or the
+    /// creator of the current resolver may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let method = rodeo.get_or_intern("crate::module::Type::method");
+    /// let other = rodeo.get_or_intern("crate::module::Type::other");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// let mut buf = String::new();
+    /// assert_eq!("crate::module::Type::method", resolver.resolve_into(&method, &mut buf));
+    /// assert_eq!("crate::module::Type::other", resolver.resolve_into(&other, &mut buf));
+    /// ```
+    ///
+    #[inline]
+    pub fn resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> &'b str {
+        self.try_resolve_into(key, buf).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents, or returns `None`
+    /// and leaves the buffer alone if the key is out of bounds. Only keys made by the current resolver
+    /// or the creator of the current resolver may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Key, Rodeo, Spur};
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("crate::module::Type::method");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// let mut buf = String::new();
+    /// assert_eq!(Some("crate::module::Type::method"), resolver.try_resolve_into(&key, &mut buf));
+    /// assert_eq!(None, resolver.try_resolve_into(&Spur::try_from_usize(10).unwrap(), &mut buf));
+    /// ```
+    ///
+    #[inline]
+    pub fn try_resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> Option<&'b str> {
+        if let Some(string) = key.as_inline::<str>() {
+            buf.clear();
+            buf.push_str(string);
+
+            return Some(buf.as_str());
+        }
+
+        let idx = self.index_of(key)?;
+
+        // Safety: Once decoding is done the buffer holds either a whole string or one that was put together
+        // from a coded string and the prefix it shares, which were all valid strings when coded
+        unsafe {
+            let bytes = buf.as_mut_vec();
+            if let Some(whole) = self.decode(idx, bytes) {
+                bytes.clear();
+                bytes.extend_from_slice(whole);
+            }
+        }
+
+        Some(buf.as_str())
+    }
+
+    /// Returns `true` if the given key exists in the current resolver
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Key, Rodeo, Spur};
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("crate::module::Type::method");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert!(resolver.contains_key(&key));
+    /// assert!(!resolver.contains_key(&Spur::try_from_usize(10).unwrap()));
+    /// ```
+    ///
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.index_of(key).is_some()
+    }
+
+    /// Gets the number of strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("Documentation often has little hidden bits in it");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert_eq!(resolver.len(), 1);
+    /// ```
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let rodeo = Rodeo::default();
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert!(resolver.is_empty());
+    /// ```
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the number of strings in every block
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        self.block_size.get()
+    }
+
+    /// Returns the number of bytes used by the resolver's strings and blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// for method in &["first", "second", "third"] {
+    ///     rodeo.get_or_intern(format!("crate::module::Type::{}", method));
+    /// }
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert!(resolver.size_in_bytes() < 3 * "crate::module::Type::".len());
+    /// ```
+    ///
+    #[inline]
+    pub fn size_in_bytes(&self) -> usize {
+        self.data.capacity() + self.blocks.capacity() * mem::size_of::<usize>()
+    }
+
+    /// Gets the index of a key, returning `None` if it's out of bounds
+    #[inline]
+    fn index_of(&self, key: &K) -> Option<usize> {
+        // Safety: The index is bounds checked, so a fabricated key can only fail to be found
+        let idx = unsafe { key.into_usize() };
+
+        if idx < self.len {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
+    /// Decodes the string at `idx`, returning it directly if it's stored whole or otherwise
+    /// putting it together in `buf`
+    #[inline]
+    fn decode<'a>(&'a self, idx: usize, buf: &mut Vec<u8>) -> Option<&'a [u8]> {
+        let (block, entry) = (idx / self.block_size.get(), idx % self.block_size.get());
+
+        let mut cursor = self.blocks[block];
+        let len = read_varint(&self.data, &mut cursor);
+        let head = &self.data[cursor..cursor + len];
+        cursor += len;
+
+        // The current string while it's stored whole, so that it only has to be copied into the buffer
+        // when a later string shares a prefix with it
+        let mut whole = Some(head);
+        for _ in 0..entry {
+            let shared = read_varint(&self.data, &mut cursor);
+            let len = read_varint(&self.data, &mut cursor);
+            let suffix = &self.data[cursor..cursor + len];
+            cursor += len;
+
+            if shared == 0 {
+                whole = Some(suffix);
+            } else {
+                match whole.take() {
+                    Some(previous) => {
+                        buf.clear();
+                        buf.extend_from_slice(&previous[..shared]);
+                    }
+                    None => buf.truncate(shared),
+                }
+
+                buf.extend_from_slice(suffix);
+            }
+        }
+
+        whole
+    }
+}
+
+/// Writes a number as a LEB128 varint, seven bits at a time with the high bit set on every byte but the last
+#[inline]
+fn write_varint(data: &mut Vec<u8>, mut int: usize) {
+    while int >= 0x80 {
+        data.push(int as u8 | 0x80);
+        int >>= 7;
+    }
+
+    data.push(int as u8);
+}
+
+/// Reads a LEB128 varint, moving the cursor past it
+#[inline]
+fn read_varint(data: &[u8], cursor: &mut usize) -> usize {
+    let mut int = 0;
+    let mut shift = 0;
+
+    loop {
+        let byte = data[*cursor];
+        *cursor += 1;
+
+        int |= usize::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return int;
+        }
+
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::format;
+        }
+    }
+
+    fn coded(strings: &[&str], block_size: usize) -> FrontCodedResolver<Spur> {
+        FrontCodedResolver::new(
+            &StringTable::new(strings, None),
+            NonZeroUsize::new(block_size).unwrap(),
+        )
+    }
+
+    fn key(idx: usize) -> Spur {
+        Spur::try_from_usize(idx).unwrap()
+    }
+
+    #[test]
+    fn varints() {
+        let mut data = Vec::new();
+        let ints = [0, 1, 0x7F, 0x80, 0x3FFF, 0x4000, usize::MAX];
+        for &int in &ints {
+            write_varint(&mut data, int);
+        }
+
+        let mut cursor = 0;
+        for &int in &ints {
+            assert_eq!(int, read_varint(&data, &mut cursor));
+        }
+        assert_eq!(data.len(), cursor);
+    }
+
+    #[test]
+    fn round_trip() {
+        let strings = [
+            "crate::module::Type::method",
+            "crate::module::Type::other",
+            "crate::module::Other",
+            "unrelated",
+            "",
+            "crate::module",
+            "crate::module::Type",
+            "größe",
+            "größer",
+            "grüße",
+        ];
+
+        for block_size in 1..=strings.len() + 1 {
+            let resolver = coded(&strings, block_size);
+            assert_eq!(strings.len(), resolver.len());
+
+            let mut buf = String::from("leftovers");
+            for (idx, string) in strings.iter().enumerate() {
+                assert_eq!(*string, resolver.resolve(&key(idx)));
+                assert_eq!(*string, resolver.resolve_into(&key(idx), &mut buf));
+            }
+        }
+    }
+
+    #[test]
+    fn borrows_whole_strings() {
+        let strings = ["crate::a", "crate::b", "other", "other::c"];
+        let resolver = coded(&strings, 4);
+
+        assert!(matches!(resolver.resolve(&key(0)), Cow::Borrowed(_)));
+        assert!(matches!(resolver.resolve(&key(1)), Cow::Owned(_)));
+        assert!(matches!(resolver.resolve(&key(2)), Cow::Borrowed(_)));
+        assert!(matches!(resolver.resolve(&key(3)), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn shared_prefixes_are_stored_once() {
+        let strings = (0..100)
+            .map(|i| format!("crate::module::Type::method_{}", i))
+            .collect::<Vec<_>>();
+        let strings = strings.iter().map(String::as_str).collect::<Vec<_>>();
+        let total = strings.iter().map(|string| string.len()).sum::<usize>();
+
+        let resolver = coded(&strings, 16);
+        assert!(resolver.size_in_bytes() < total / 4);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let resolver = coded(&["a"], 16);
+        let mut buf = String::from("untouched");
+
+        assert!(!resolver.contains_key(&key(1)));
+        assert_eq!(None, resolver.try_resolve(&key(1)));
+        assert_eq!(None, resolver.try_resolve_into(&key(1), &mut buf));
+        assert_eq!("untouched", buf);
+    }
+
+    #[test]
+    fn empty() {
+        let resolver = coded(&[], 16);
+
+        assert!(resolver.is_empty());
+        assert_eq!(None, resolver.try_resolve(&key(0)));
+    }
+}
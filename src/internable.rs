@@ -1,6 +1,25 @@
 use core::hash::Hash;
 
 /// Represents something that's able to be interned
+///
+/// Along with `str`, `CStr`, `OsStr`, `Path` and slices, any sized type that is `Hash + Eq + Clone`
+/// is internable, allowing things like tuples of keys or small structs to be interned directly
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{Rodeo, Spur};
+///
+/// let mut strings = Rodeo::default();
+/// let (a, b) = (strings.get_or_intern("a"), strings.get_or_intern("b"));
+///
+/// let mut pairs: Rodeo<(Spur, Spur)> = Rodeo::new();
+/// let pair = pairs.get_or_intern((a, b));
+///
+/// assert_eq!(&(a, b), pairs.resolve(&pair));
+/// assert_eq!(Some(pair), pairs.get(&(a, b)));
+/// ```
+///
 pub trait Internable: Hash + Eq + 'static {
     /// The raw data that is stored in the interner
     type Raw: Sized + Clone;
@@ -19,10 +38,12 @@ pub trait Internable: Hash + Eq + 'static {
 impl Internable for str {
     type Raw = u8;
 
+    #[inline]
     fn to_raw(&self) -> &[Self::Raw] {
         self.as_bytes()
     }
 
+    #[inline]
     unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
         core::str::from_utf8_unchecked(raw)
     }
@@ -32,58 +53,164 @@ impl Internable for str {
 impl Internable for std::ffi::CStr {
     type Raw = u8;
 
+    #[inline]
     fn to_raw(&self) -> &[Self::Raw] {
         self.to_bytes_with_nul()
     }
 
+    #[inline]
     unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
         std::ffi::CStr::from_bytes_with_nul(raw).unwrap()
     }
 }
 
+#[cfg(not(feature = "no-std"))]
+impl Internable for std::ffi::OsStr {
+    type Raw = u8;
+
+    #[inline]
+    fn to_raw(&self) -> &[Self::Raw] {
+        self.as_encoded_bytes()
+    }
+
+    #[inline]
+    unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
+        // Safety: The raw bytes were produced by `as_encoded_bytes` within the current process
+        std::ffi::OsStr::from_encoded_bytes_unchecked(raw)
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl Internable for std::path::Path {
+    type Raw = u8;
+
+    #[inline]
+    fn to_raw(&self) -> &[Self::Raw] {
+        self.as_os_str().as_encoded_bytes()
+    }
+
+    #[inline]
+    unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
+        std::path::Path::new(std::ffi::OsStr::from_raw(raw))
+    }
+}
+
 impl<T> Internable for [T]
 where
     T: Hash + Eq + 'static + Sized + Clone,
 {
     type Raw = T;
 
+    #[inline]
     fn to_raw(&self) -> &[Self::Raw] {
         self
     }
 
+    #[inline]
     unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
         raw
     }
 }
 
-// TODO: It *should* be possible to use this with arbitrary types, but as of now,
-//       it produces hellish errors
-// impl<T> Internable for T
-// where
-//     T: Hash + Eq + 'static + Sized + Clone,
-// {
-//     type Raw = Self;
-//
-//     fn to_raw(&self) -> &[Self::Raw] {
-//         core::slice::from_ref(self)
-//     }
-//
-//     unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
-//         &raw[0]
-//     }
-// }
-//
-// impl<T> Internable for [T]
-// where
-//     T: Internable + Clone,
-// {
-//     type Raw = T;
-//
-//     fn to_raw(&self) -> &[Self::Raw] {
-//         self
-//     }
-//
-//     unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
-//         raw
-//     }
-// }
+impl<T> Internable for T
+where
+    T: Hash + Eq + 'static + Sized + Clone,
+{
+    type Raw = Self;
+
+    #[inline]
+    fn to_raw(&self) -> &[Self::Raw] {
+        core::slice::from_ref(self)
+    }
+
+    #[inline]
+    unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
+        &raw[0]
+    }
+}
+
+/// Things that can be turned into a reference to an [`Internable`] value, used as the input
+/// for all interning and lookup methods
+///
+/// Anything that is `AsRef<str>`, `AsRef<CStr>`, `AsRef<OsStr>`, `AsRef<Path>` or `AsRef<[T]>`
+/// can be used for the respective unsized types, while sized values can be given either by value or by reference
+///
+/// [`Internable`]: crate::Internable
+pub trait AsInternable<V: Internable + ?Sized> {
+    /// Gets a reference to the internable value
+    fn as_internable(&self) -> &V;
+}
+
+impl<T> AsInternable<str> for T
+where
+    T: AsRef<str> + ?Sized,
+{
+    #[inline]
+    fn as_internable(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl<T> AsInternable<std::ffi::CStr> for T
+where
+    T: AsRef<std::ffi::CStr> + ?Sized,
+{
+    #[inline]
+    fn as_internable(&self) -> &std::ffi::CStr {
+        self.as_ref()
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl<T> AsInternable<std::ffi::OsStr> for T
+where
+    T: AsRef<std::ffi::OsStr> + ?Sized,
+{
+    #[inline]
+    fn as_internable(&self) -> &std::ffi::OsStr {
+        self.as_ref()
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl<T> AsInternable<std::path::Path> for T
+where
+    T: AsRef<std::path::Path> + ?Sized,
+{
+    #[inline]
+    fn as_internable(&self) -> &std::path::Path {
+        self.as_ref()
+    }
+}
+
+impl<U, T> AsInternable<[U]> for T
+where
+    U: Hash + Eq + 'static + Sized + Clone,
+    T: AsRef<[U]> + ?Sized,
+{
+    #[inline]
+    fn as_internable(&self) -> &[U] {
+        self.as_ref()
+    }
+}
+
+impl<V> AsInternable<V> for V
+where
+    V: Hash + Eq + 'static + Sized + Clone,
+{
+    #[inline]
+    fn as_internable(&self) -> &V {
+        self
+    }
+}
+
+impl<V> AsInternable<V> for &V
+where
+    V: Hash + Eq + 'static + Sized + Clone,
+{
+    #[inline]
+    fn as_internable(&self) -> &V {
+        self
+    }
+}
@@ -1,4 +1,10 @@
-use core::num::{NonZeroU16, NonZeroU32, NonZeroU8, NonZeroUsize};
+use crate::internable::Internable;
+
+use core::{
+    any::TypeId,
+    num::{NonZeroU16, NonZeroU32, NonZeroU8, NonZeroUsize},
+    slice, str,
+};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +27,52 @@ pub unsafe trait Key: Copy + Eq {
 
     /// Attempts to create a key from a `usize`, returning `None` if it fails
     fn try_from_usize(int: usize) -> Option<Self>;
+
+    /// Attempts to create a key that holds `value` within itself instead of an index, returning `None`
+    /// if the key can't hold it, which by default is always
+    ///
+    /// Interners that compare values exactly try this before looking up or storing a value, so values
+    /// that fit within a key are never stored. Keys created this way can only be resolved by [`as_inline`]
+    ///
+    /// [`as_inline`]: Key::as_inline
+    #[inline]
+    fn try_inline<V>(value: &V) -> Option<Self>
+    where
+        V: Internable + ?Sized,
+    {
+        let _ = value;
+        None
+    }
+
+    /// Gets the value held within the key, returning `None` if it holds an index instead or holds
+    /// something other than a `V`, which by default is always
+    ///
+    /// Interners resolve keys with this in `resolve_into` before using them as an index
+    #[inline]
+    fn as_inline<V>(&self) -> Option<&V>
+    where
+        V: Internable + ?Sized,
+    {
+        None
+    }
+
+    /// Returns `true` if the key holds a value instead of an index, which by default is never
+    #[inline]
+    fn is_inline(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if keys can hold values of `V` within themselves, which by default is never
+    ///
+    /// Values held within keys are never stored, so read-only interners refuse to build indices over
+    /// their strings with keys that can hold them, since the indices would miss them
+    #[inline]
+    fn can_inline<V>() -> bool
+    where
+        V: Internable + ?Sized,
+    {
+        false
+    }
 }
 
 /// The default key for every Rodeo, the same size as a `usize`
@@ -45,7 +97,7 @@ unsafe impl Key for LargeSpur {
     /// Returns `None` if `int` is greater than `usize::MAX - 1`
     #[inline]
     fn try_from_usize(int: usize) -> Option<Self> {
-        if int < usize::max_value() {
+        if int < usize::MAX {
             // Safety: The integer is less than the max value and then incremented by one, meaning that
             // is is impossible for a zero to inhabit the NonZeroUsize
             unsafe {
@@ -88,7 +140,7 @@ unsafe impl Key for Spur {
     /// Returns `None` if `int` is greater than `usize::MAX - 1`
     #[inline]
     fn try_from_usize(int: usize) -> Option<Self> {
-        if int < u32::max_value() as usize {
+        if int < u32::MAX as usize {
             // Safety: The integer is less than the max value and then incremented by one, meaning that
             // is is impossible for a zero to inhabit the NonZeroU32
             unsafe {
@@ -131,7 +183,7 @@ unsafe impl Key for MiniSpur {
     /// Returns `None` if `int` is greater than `usize::MAX - 1`
     #[inline]
     fn try_from_usize(int: usize) -> Option<Self> {
-        if int < u16::max_value() as usize {
+        if int < u16::MAX as usize {
             // Safety: The integer is less than the max value and then incremented by one, meaning that
             // is is impossible for a zero to inhabit the NonZeroU16
             unsafe {
@@ -174,7 +226,7 @@ unsafe impl Key for MicroSpur {
     /// Returns `None` if `int` is greater than `usize::MAX - 1`
     #[inline]
     fn try_from_usize(int: usize) -> Option<Self> {
-        if int < u8::max_value() as usize {
+        if int < u8::MAX as usize {
             // Safety: The integer is less than the max value and then incremented by one, meaning that
             // is is impossible for a zero to inhabit the NonZeroU16
             unsafe {
@@ -195,6 +247,232 @@ impl Default for MicroSpur {
     }
 }
 
+/// A 32 bit key that can hold strings of up to [`MAX_INLINE_LEN`] bytes within itself, so that short
+/// strings like operators and single letter identifiers never have to be stored by an interner
+///
+/// Interners of `str` that compare strings exactly and aren't nul terminated hold every short string
+/// within its key, so `get_or_intern` and `get` give the same key for a short string every time. The
+/// string can't be borrowed from the interner, so it's read back out of the key with `resolve_into`
+/// while `resolve` panics and `try_resolve` returns `None`
+///
+/// # Short strings are always interned
+///
+/// Since short strings are never stored, the interner can't tell which of them were interned: `get` and
+/// `contains` find **every** string of up to [`MAX_INLINE_LEN`] bytes, even ones that were never given to
+/// `get_or_intern`. They also aren't counted by `len` or yielded by `iter` and `strings`, and read-only
+/// interners refuse to build their prefix, substring, nearest and rank indices with these keys
+///
+/// Internally the first byte is a non-zero tag to allow for space optimizations when stored inside of
+/// an [`Option`], and keys that hold a string compare greater than every other key
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{InlineSpur, Rodeo};
+///
+/// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+///
+/// // Held within the key, the Rodeo doesn't store anything
+/// let plus = rodeo.get_or_intern("+");
+/// assert!(plus.is_inline());
+/// assert!(rodeo.is_empty());
+/// assert_eq!(Some(plus), rodeo.get("+"));
+///
+/// let mut buf = String::new();
+/// assert_eq!("+", rodeo.resolve_into(&plus, &mut buf));
+///
+/// // Never interned, but found all the same
+/// assert!(rodeo.get("-").is_some());
+///
+/// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+/// assert!(!long.is_inline());
+/// assert_eq!(1, rodeo.len());
+/// ```
+///
+/// [`MAX_INLINE_LEN`]: InlineSpur::MAX_INLINE_LEN
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+#[cfg_attr(feature = "serialize", derive(Deserialize, Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct InlineSpur {
+    /// The length of the string with the high bit set if the key holds one, otherwise the high byte of
+    /// the index plus one
+    tag: NonZeroU8,
+    /// The string padded with zeroes if the key holds one, otherwise the low bytes of the index in
+    /// big endian order so that keys compare in the order of their indices
+    bytes: [u8; 3],
+}
+
+impl InlineSpur {
+    /// The length in bytes of the longest string that can be held within a key
+    pub const MAX_INLINE_LEN: usize = 3;
+
+    /// The bit of the tag that's set when the key holds a string
+    const INLINE_TAG: u8 = 0x80;
+
+    /// Creates a key holding the given string, returning `None` if it's longer than [`MAX_INLINE_LEN`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::InlineSpur;
+    ///
+    /// let key = InlineSpur::try_inline("+=").unwrap();
+    /// assert_eq!(Some("+="), key.as_inline());
+    ///
+    /// assert_eq!(None, InlineSpur::try_inline("four"));
+    /// ```
+    ///
+    /// [`MAX_INLINE_LEN`]: InlineSpur::MAX_INLINE_LEN
+    #[inline]
+    pub fn try_inline(string: &str) -> Option<Self> {
+        if string.len() > Self::MAX_INLINE_LEN {
+            return None;
+        }
+
+        let mut bytes = [0; 3];
+        bytes[..string.len()].copy_from_slice(string.as_bytes());
+
+        // Safety: The tag bit is set, so the tag isn't zero
+        let tag = unsafe { NonZeroU8::new_unchecked(Self::INLINE_TAG | string.len() as u8) };
+
+        Some(Self { tag, bytes })
+    }
+
+    /// Gets the string held within the key, returning `None` if it doesn't hold one
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key};
+    ///
+    /// assert_eq!(Some("if"), InlineSpur::try_inline("if").unwrap().as_inline());
+    /// assert_eq!(None, InlineSpur::try_from_usize(0).unwrap().as_inline());
+    /// ```
+    ///
+    #[inline]
+    pub fn as_inline(&self) -> Option<&str> {
+        if !self.is_inline() {
+            return None;
+        }
+
+        // Keys are only given strings by `try_inline`, but deserialized keys could hold anything
+        let len = (self.tag.get() & !Self::INLINE_TAG) as usize;
+        self.bytes
+            .get(..len)
+            .and_then(|bytes| str::from_utf8(bytes).ok())
+    }
+
+    /// Returns `true` if the key holds a string instead of an index
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key};
+    ///
+    /// assert!(InlineSpur::try_inline("").unwrap().is_inline());
+    /// assert!(!InlineSpur::try_from_usize(0).unwrap().is_inline());
+    /// ```
+    ///
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.tag.get() & Self::INLINE_TAG != 0
+    }
+}
+
+unsafe impl Key for InlineSpur {
+    #[inline]
+    unsafe fn into_usize(self) -> usize {
+        let [high, mid, low] = self.bytes;
+
+        // Keys holding a string give indices past any that `try_from_usize` accepts, so they're
+        // always out of bounds
+        u32::from_be_bytes([self.tag.get(), high, mid, low]) as usize - (1 << 24)
+    }
+
+    /// Returns `None` if `int` needs more than 31 bits once incremented by one, the rest are used
+    /// for keys holding strings
+    #[inline]
+    fn try_from_usize(int: usize) -> Option<Self> {
+        if int < ((Self::INLINE_TAG as usize - 1) << 24) {
+            let [_, high, mid, low] = (int as u32).to_be_bytes();
+
+            // Safety: The integer's high byte is less than `0x7F`, so incrementing it by one can't
+            // overflow or make it zero
+            Some(Self {
+                tag: unsafe { NonZeroU8::new_unchecked((int >> 24) as u8 + 1) },
+                bytes: [high, mid, low],
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Holds `value` within the key if it's a `str` no longer than [`MAX_INLINE_LEN`]
+    ///
+    /// [`MAX_INLINE_LEN`]: InlineSpur::MAX_INLINE_LEN
+    #[inline]
+    fn try_inline<V>(value: &V) -> Option<Self>
+    where
+        V: Internable + ?Sized,
+    {
+        if TypeId::of::<V>() != TypeId::of::<str>() {
+            return None;
+        }
+
+        let raw = value.to_raw();
+
+        // Safety: `V` is `str`, so its raw items are the bytes of a valid string
+        let string = unsafe {
+            str::from_utf8_unchecked(slice::from_raw_parts(raw.as_ptr() as *const u8, raw.len()))
+        };
+
+        Self::try_inline(string)
+    }
+
+    /// Gets the `str` held within the key
+    #[inline]
+    fn as_inline<V>(&self) -> Option<&V>
+    where
+        V: Internable + ?Sized,
+    {
+        if TypeId::of::<V>() != TypeId::of::<str>() {
+            return None;
+        }
+
+        let string = Self::as_inline(self)?;
+
+        // Safety: `V` is `str`, so its raw items are bytes and a valid string makes a valid `V`
+        Some(unsafe {
+            V::from_raw(slice::from_raw_parts(
+                string.as_ptr() as *const V::Raw,
+                string.len(),
+            ))
+        })
+    }
+
+    #[inline]
+    fn is_inline(&self) -> bool {
+        Self::is_inline(self)
+    }
+
+    /// Returns `true` if `V` is `str`
+    #[inline]
+    fn can_inline<V>() -> bool
+    where
+        V: Internable + ?Sized,
+    {
+        TypeId::of::<V>() == TypeId::of::<str>()
+    }
+}
+
+impl Default for InlineSpur {
+    #[inline]
+    fn default() -> Self {
+        Self::try_from_usize(1).unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,93 +480,152 @@ mod tests {
     #[test]
     fn large() {
         let zero = LargeSpur::try_from_usize(0).unwrap();
-        let max = LargeSpur::try_from_usize(usize::max_value() - 1).unwrap();
+        let max = LargeSpur::try_from_usize(usize::MAX - 1).unwrap();
 
         unsafe {
             assert_eq!(zero.into_usize(), 0);
-            assert_eq!(max.into_usize(), usize::max_value() - 1);
+            assert_eq!(max.into_usize(), usize::MAX - 1);
         }
     }
 
     #[test]
     fn large_max_returns_none() {
-        assert_eq!(None, LargeSpur::try_from_usize(usize::max_value()));
+        assert_eq!(None, LargeSpur::try_from_usize(usize::MAX));
     }
 
     #[test]
     #[should_panic]
     #[cfg(not(miri))]
     fn large_max_panics() {
-        LargeSpur::try_from_usize(usize::max_value()).unwrap();
+        LargeSpur::try_from_usize(usize::MAX).unwrap();
     }
 
     #[test]
     fn spur() {
         let zero = Spur::try_from_usize(0).unwrap();
-        let max = Spur::try_from_usize(u32::max_value() as usize - 1).unwrap();
+        let max = Spur::try_from_usize(u32::MAX as usize - 1).unwrap();
 
         unsafe {
             assert_eq!(zero.into_usize(), 0);
-            assert_eq!(max.into_usize(), u32::max_value() as usize - 1);
+            assert_eq!(max.into_usize(), u32::MAX as usize - 1);
         }
     }
 
     #[test]
     fn spur_returns_none() {
-        assert_eq!(None, Spur::try_from_usize(u32::max_value() as usize));
+        assert_eq!(None, Spur::try_from_usize(u32::MAX as usize));
     }
 
     #[test]
     #[should_panic]
     #[cfg(not(miri))]
     fn spur_panics() {
-        Spur::try_from_usize(u32::max_value() as usize).unwrap();
+        Spur::try_from_usize(u32::MAX as usize).unwrap();
     }
 
     #[test]
     fn mini() {
         let zero = MiniSpur::try_from_usize(0).unwrap();
-        let max = MiniSpur::try_from_usize(u16::max_value() as usize - 1).unwrap();
+        let max = MiniSpur::try_from_usize(u16::MAX as usize - 1).unwrap();
 
         unsafe {
             assert_eq!(zero.into_usize(), 0);
-            assert_eq!(max.into_usize(), u16::max_value() as usize - 1);
+            assert_eq!(max.into_usize(), u16::MAX as usize - 1);
         }
     }
 
     #[test]
     fn mini_returns_none() {
-        assert_eq!(None, MiniSpur::try_from_usize(u16::max_value() as usize));
+        assert_eq!(None, MiniSpur::try_from_usize(u16::MAX as usize));
     }
 
     #[test]
     #[should_panic]
     #[cfg(not(miri))]
     fn mini_panics() {
-        MiniSpur::try_from_usize(u16::max_value() as usize).unwrap();
+        MiniSpur::try_from_usize(u16::MAX as usize).unwrap();
     }
 
     #[test]
     fn micro() {
         let zero = MicroSpur::try_from_usize(0).unwrap();
-        let max = MicroSpur::try_from_usize(u8::max_value() as usize - 1).unwrap();
+        let max = MicroSpur::try_from_usize(u8::MAX as usize - 1).unwrap();
 
         unsafe {
             assert_eq!(zero.into_usize(), 0);
-            assert_eq!(max.into_usize(), u8::max_value() as usize - 1);
+            assert_eq!(max.into_usize(), u8::MAX as usize - 1);
         }
     }
 
     #[test]
     fn micro_returns_none() {
-        assert_eq!(None, MicroSpur::try_from_usize(u8::max_value() as usize));
+        assert_eq!(None, MicroSpur::try_from_usize(u8::MAX as usize));
     }
 
     #[test]
     #[should_panic]
     #[cfg(not(miri))]
     fn micro_panics() {
-        MicroSpur::try_from_usize(u8::max_value() as usize).unwrap();
+        MicroSpur::try_from_usize(u8::MAX as usize).unwrap();
+    }
+
+    #[test]
+    fn inline() {
+        let zero = InlineSpur::try_from_usize(0).unwrap();
+        let max = InlineSpur::try_from_usize(0x7EFF_FFFF).unwrap();
+
+        unsafe {
+            assert_eq!(zero.into_usize(), 0);
+            assert_eq!(max.into_usize(), 0x7EFF_FFFF);
+        }
+        assert!(zero < max);
+        assert!(!max.is_inline());
+        assert_eq!(None, max.as_inline());
+    }
+
+    #[test]
+    fn inline_returns_none() {
+        assert_eq!(None, InlineSpur::try_from_usize(0x7F00_0000));
+        assert_eq!(None, InlineSpur::try_from_usize(usize::MAX));
+    }
+
+    #[test]
+    fn inline_strings() {
+        for &string in &["", "a", "+=", "...", "ü", "€"] {
+            let key = InlineSpur::try_inline(string).unwrap();
+
+            assert!(key.is_inline());
+            assert_eq!(Some(string), key.as_inline());
+            assert!(key > InlineSpur::try_from_usize(0x7EFF_FFFF).unwrap());
+            assert!(unsafe { key.into_usize() } >= 0x7F00_0000);
+        }
+
+        assert_ne!(
+            InlineSpur::try_inline("a").unwrap(),
+            InlineSpur::try_inline("a\0").unwrap()
+        );
+        assert_eq!(None, InlineSpur::try_inline("four"));
+        assert_eq!(None, InlineSpur::try_inline("ü€"));
+    }
+
+    #[test]
+    fn inline_hooks() {
+        let key = <InlineSpur as Key>::try_inline::<str>("+=").unwrap();
+        assert!(Key::is_inline(&key));
+        assert_eq!(Some("+="), Key::as_inline::<str>(&key));
+        assert_eq!(None, <InlineSpur as Key>::try_inline::<str>("four"));
+
+        // Only strings are held within keys
+        assert_eq!(None, <InlineSpur as Key>::try_inline::<[u8]>(b"+="));
+        assert_eq!(None, Key::as_inline::<[u8]>(&key));
+
+        let index = InlineSpur::try_from_usize(0).unwrap();
+        assert!(!Key::is_inline(&index));
+        assert_eq!(None, Key::as_inline::<str>(&index));
+
+        let spur = Spur::try_from_usize(0).unwrap();
+        assert_eq!(None, <Spur as Key>::try_inline::<str>("+"));
+        assert!(!spur.is_inline());
     }
 
     #[test]
@@ -305,5 +642,8 @@ mod tests {
 
         let micro = MicroSpur::try_from_usize(0).unwrap();
         let _ = serde_json::to_string(&micro).unwrap();
+
+        let inline = InlineSpur::try_inline("+").unwrap();
+        let _ = serde_json::to_string(&inline).unwrap();
     }
 }
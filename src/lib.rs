@@ -1,5 +1,4 @@
 #![cfg_attr(feature = "no-std", no_std)]
-#![cfg_attr(feature = "nightly", feature(hash_raw_entry))]
 #![warn(clippy::missing_inline_in_public_items)]
 #![deny(
     missing_docs,
@@ -39,14 +38,13 @@
 //! ## Cargo Features
 //!
 //!
-//! By default `lasso` has zero dependencies and only [`Rodeo`] is exposed. To make use of [`ThreadedRodeo`], you must enable the `multi-threaded` feature.
+//! By default `lasso`'s only dependency is [`hashbrown`] and only [`Rodeo`] is exposed. To make use of [`ThreadedRodeo`], you must enable the `multi-threaded` feature.
 //!
 //! * `multi-threaded` - Enables [`ThreadedRodeo`], the interner for multi-threaded tasks
 //! * `hashbrown-table` - Uses [`hashbrown`] as the internal `HashMap`
 //! * `ahasher` - Use [`ahash`]'s `RandomState` as the default hasher
 //! * `no-std` - Enables `no_std` + `alloc` support for [`Rodeo`] and [`ThreadedRodeo`]
 //!   * Automatically enables the following required features:
-//!     * `dashmap/no_std` - `no_std` compatibility for `DashMap`
 //!     * `hashbrown-table` - `no_std` `HashMap`
 //!     * `ahasher` - `no_std` hashing function
 //! * `serialize` - Implements `Serialize` and `Deserialize` for all `Spur` types
@@ -318,19 +316,6 @@
 //! | `get` (empty)            | 40.496 μs | 646.72 MiB/s |         -10.80%          |
 //! | `get` (filled)           | 63.797 μs | 410.52 MiB/s |         -20.19%          |
 //!
-//! ### Nightly Benches
-//!
-//! When the `nightly` feature is enabled, this is the performance you can expect from `Rodeo`.  
-//! The functions listed are the ones currently affected by the changes of the `nightly` feature, and the benchmarks were preformed with std's RandomState.  
-//! Testing was done on Rust Nightly 1.45.0
-//!
-//! | Method                       |   Time    |  Throughput  | Relative Perf vs Stable |
-//! | :--------------------------- | :-------: | :----------: | :---------------------: |
-//! | `get_or_intern` (empty)      | 94.516 μs | 277.09 MiB/s |         +0.73%          |
-//! | `get_or_intern` (filled)     | 56.716 μs | 461.77 MiB/s |         +0.78%          |
-//! | `try_get_or_intern` (empty)  | 94.629 μs | 276.76 MiB/s |         -1.188%         |
-//! | `try_get_or_intern` (filled) | 56.839 μs | 460.77 MiB/s |         +0.336%         |
-//!
 //! [0]: https://github.com/Kixiron/lasso
 //! [1]: https://github.com/Kixiron/lasso/workflows/CI/badge.svg
 //! [2]: https://github.com/Kixiron/lasso/workflows/Security%20Audit/badge.svg
@@ -349,23 +334,39 @@
 //! [`hashbrown`]: https://crates.io/crates/hashbrown
 //! [`ahash`]: https://crates.io/crates/ahash
 //! [`string-interner`]: https://github.com/Robbepop/string-interner
-//! [Nightly Benches]: #nightly-benches
 
 #[macro_use]
 mod util;
 
 // mod unique; // Experimental, doesn't currently work
-mod arena;
+mod bloom;
+mod front_coded;
 mod internable;
 mod key;
+mod nearest;
+mod normalizer;
+mod prefix;
+mod rank;
 mod reader;
+mod remap;
 mod resolver;
 mod single_threaded;
+mod sorted;
+mod substring;
+mod table;
+mod utf16;
 
-pub use key::{Key, LargeSpur, MicroSpur, MiniSpur, Spur};
+pub use front_coded::FrontCodedResolver;
+pub use internable::{AsInternable, Internable};
+pub use key::{InlineSpur, Key, LargeSpur, MicroSpur, MiniSpur, Spur};
+pub use normalizer::{AsciiCaseInsensitive, AsciiLowercase, Identity, Normalizer, SimpleCaseFold};
+pub use rank::RankTable;
 pub use reader::RodeoReader;
+pub use remap::KeyRemap;
 pub use resolver::RodeoResolver;
 pub use single_threaded::Rodeo;
+pub use sorted::SortedReader;
+pub use utf16::{Utf16Error, Utf16Str, Utf16String};
 
 compile! {
     if #[feature = "no-std"] {
@@ -373,8 +374,11 @@ compile! {
     }
 
     if #[all(feature = "multi-threaded", not(feature = "no-std"))] {
+        mod arena;
         mod multi_threaded;
-        pub use multi_threaded::ThreadedRodeo;
+        mod segmented;
+        mod sharded;
+        pub use multi_threaded::{ThreadedRodeo, ThreadedRodeoConfig};
     }
 }
 
@@ -386,16 +390,11 @@ mod hasher {
         } else {
             pub use std::collections::hash_map::RandomState;
         }
-
-        if #[feature = "hashbrown-table"] {
-            pub use hashbrown::HashMap;
-        } else {
-            pub use std::collections::HashMap;
-        }
     }
 }
 
 #[doc(hidden)]
+#[allow(unused_imports)]
 mod locks {
     compile! {
         if #[feature = "no-std"] {
@@ -1,44 +1,184 @@
 use crate::{
     arena::Arena,
-    hasher::{HashMap, RandomState},
-    internable::Internable,
+    hasher::RandomState,
+    internable::{AsInternable, Internable},
     key::{Key, Spur},
+    normalizer::{Identity, Normalized, Normalizer},
     reader::RodeoReader,
+    remap::KeyRemap,
     resolver::RodeoResolver,
+    segmented::SegmentedVec,
+    sharded::{default_shard_amount, ConcurrentKeyMap, DefaultKeyMap, MAX_SHARD_AMOUNT},
+    table::StringTable,
+    util::{inline_key, key_map},
 };
 
 use core::{
     hash::{BuildHasher, Hash},
-    iter, mem,
+    marker::PhantomData,
+    mem,
+    ops::Range,
     sync::atomic::{AtomicUsize, Ordering},
 };
-use dashmap::DashMap;
 use std::sync::Mutex;
 
 /// A concurrent string interner that caches strings quickly with a minimal memory footprint,
 /// returning a unique key to re-access it with `O(1)` internment and resolution.
 ///
 /// This struct is only avaliable with the `multi-threaded` feature!  
-/// By default ThreadedRodeo uses the [`Spur`] type for keys and [`RandomState`] as the hasher
+/// By default ThreadedRodeo uses the [`Spur`] type for keys, [`RandomState`] as the hasher and
+/// compares strings exactly, which can be changed with a [`Normalizer`]
 ///
 /// [`Spur`]: crate::Spur
+/// [`Normalizer`]: crate::Normalizer
 /// [`ahash::RandomState`]: https://docs.rs/ahash/0.3.2/ahash/struct.RandomState.html
 /// [`RandomState`]: index.html#cargo-features
 #[derive(Debug)]
-pub struct ThreadedRodeo<V = str, K = Spur, S = RandomState>
+pub struct ThreadedRodeo<V = str, K = Spur, S = RandomState, N = Identity>
 where
     V: Internable + ?Sized,
     K: Key + Hash,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
-    /// Map that allows str to key resolution
-    map: DashMap<&'static V, K, S>,
-    /// Map that allows key to str resolution
-    strings: DashMap<K, &'static V, S>,
-    /// The current key value
+    /// Map that allows str to key resolution, split into separately locked shards that only hold keys
+    map: DefaultKeyMap<K>,
+    /// The hasher that every string is hashed with once to find it in the map
+    hash_builder: S,
+    /// Table that allows key to str resolution without locking, along with the hash of each string. Declared
+    /// before the arena so that it's dropped before the strings it references
+    strings: SegmentedVec<(&'static V, u64)>,
+    /// The first index that hasn't been reserved for a key yet
     key: AtomicUsize,
+    /// The number of indices each shard of the map reserves at once for its keys, where keys are only
+    /// dense when it's one
+    key_batch_size: usize,
     /// The arena where all strings are stored
     arena: Mutex<Arena<V::Raw>>,
+    __normalizer: PhantomData<N>,
+}
+
+/// Chooses how a [`ThreadedRodeo`] lays out its map and hands out keys, given to [`ThreadedRodeo::with_config`]
+/// or [`ThreadedRodeo::with_config_and_hasher`]
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+///
+/// let config = ThreadedRodeoConfig::new().capacity(1024).shard_amount(64);
+/// let rodeo: ThreadedRodeo<str, Spur> = ThreadedRodeo::with_config(config);
+///
+/// assert!(rodeo.capacity() >= 1024);
+/// assert_eq!(64, rodeo.shard_amount());
+/// assert_eq!(1, rodeo.key_batch_size());
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ThreadedRodeoConfig {
+    capacity: usize,
+    shard_amount: usize,
+    key_batch_size: usize,
+}
+
+impl ThreadedRodeoConfig {
+    /// Creates a config for an empty ThreadedRodeo with the default amount of shards, where every key is
+    /// taken from a single counter so that keys are dense
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            capacity: 0,
+            shard_amount: default_shard_amount(),
+            key_batch_size: 1,
+        }
+    }
+
+    /// Sets the number of strings the ThreadedRodeo can hold without reallocating, which is zero by default
+    #[inline]
+    #[must_use]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Splits the ThreadedRodeo's map into `shard_amount` shards, which are each locked on their own. More
+    /// shards let more threads intern strings at once without contending on a lock, by default there are at
+    /// least four shards per thread the machine can run at once
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shard_amount` isn't a power of two or is larger than `65536`
+    ///
+    #[inline]
+    #[must_use]
+    pub fn shard_amount(mut self, shard_amount: usize) -> Self {
+        assert!(
+            shard_amount.is_power_of_two() && shard_amount <= MAX_SHARD_AMOUNT,
+            "The shard amount must be a power of two no larger than {}",
+            MAX_SHARD_AMOUNT,
+        );
+
+        self.shard_amount = shard_amount;
+        self
+    }
+
+    /// Makes each shard of the ThreadedRodeo's map reserve `batch_size` keys at once instead of taking every
+    /// key from a single counter shared by all threads, which keeps threads that intern new strings at the
+    /// same time from contending on the counter. The batch size is one by default
+    ///
+    /// Keys stay unique, but aren't dense anymore since a shard's unused keys are skipped over, so fewer
+    /// strings can be interned before running out of keys. Converting into a [`RodeoReader`] or
+    /// [`RodeoResolver`] makes the keys dense again, so strings may get new keys that have to be found with
+    /// [`into_reader_with_remap`] or [`into_resolver_with_remap`]
+    ///
+    /// A shard never reserves more keys at once than it already holds plus one, so its batches only reach
+    /// `batch_size` once it holds that many strings and at most as many keys are skipped over as there are
+    /// strings, plus one for every shard. The table that resolves keys has a slot for every key that was
+    /// reserved, so it can take up to about twice the memory it would with dense keys. Since keys can't be
+    /// counted by the table anymore, [`len`] locks every shard of the map to count the keys within it
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur> =
+    ///     ThreadedRodeo::with_config(ThreadedRodeoConfig::new().key_batch_size(64));
+    /// let hello = rodeo.get_or_intern("Hello");
+    /// let world = rodeo.get_or_intern("World");
+    /// assert_eq!("Hello", rodeo.resolve(&hello));
+    ///
+    /// // The keys are only remapped if any of them were skipped over
+    /// let (reader, remap) = rodeo.into_reader_with_remap();
+    /// let remap = |key| remap.as_ref().map_or(key, |remap| remap.remap(&key));
+    /// assert_eq!("Hello", reader.resolve(&remap(hello)));
+    /// assert_eq!("World", reader.resolve(&remap(world)));
+    /// ```
+    ///
+    /// [`RodeoReader`]: crate::RodeoReader
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    /// [`into_reader_with_remap`]: ThreadedRodeo::into_reader_with_remap
+    /// [`into_resolver_with_remap`]: ThreadedRodeo::into_resolver_with_remap
+    /// [`len`]: ThreadedRodeo::len
+    #[inline]
+    #[must_use]
+    pub fn key_batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size != 0, "The key batch size must be at least one");
+
+        self.key_batch_size = batch_size;
+        self
+    }
+}
+
+impl Default for ThreadedRodeoConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // TODO: More parity functions with std::HashMap
@@ -73,10 +213,13 @@ where
     #[inline]
     pub fn new() -> Self {
         Self {
-            map: DashMap::with_hasher(RandomState::new()),
-            strings: DashMap::with_hasher(RandomState::new()),
+            map: DefaultKeyMap::with_capacity_and_shard_amount(0, default_shard_amount()),
+            hash_builder: RandomState::new(),
+            strings: SegmentedVec::new(),
             key: AtomicUsize::new(0),
+            key_batch_size: 1,
             arena: Mutex::new(Arena::new()),
+            __normalizer: PhantomData,
         }
     }
 
@@ -94,12 +237,35 @@ where
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            map: DashMap::with_capacity_and_hasher(capacity, RandomState::new()),
-            strings: DashMap::with_capacity_and_hasher(capacity, RandomState::new()),
+            map: DefaultKeyMap::with_capacity_and_shard_amount(capacity, default_shard_amount()),
+            hash_builder: RandomState::new(),
+            strings: SegmentedVec::with_capacity(capacity),
             key: AtomicUsize::new(0),
+            key_batch_size: 1,
             arena: Mutex::new(Arena::new()),
+            __normalizer: PhantomData,
         }
     }
+
+    /// Create a new ThreadedRodeo laid out by the given [`ThreadedRodeoConfig`], which chooses how many
+    /// shards its map is split into and how many keys each shard reserves at once
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur> =
+    ///     ThreadedRodeo::with_config(ThreadedRodeoConfig::new().capacity(10).shard_amount(512));
+    /// assert!(rodeo.capacity() >= 10);
+    /// assert_eq!(512, rodeo.shard_amount());
+    /// ```
+    ///
+    /// [`ThreadedRodeoConfig`]: crate::ThreadedRodeoConfig
+    #[inline]
+    pub fn with_config(config: ThreadedRodeoConfig) -> Self {
+        Self::with_config_and_hasher(config, RandomState::new())
+    }
 }
 
 impl<V, K, S> ThreadedRodeo<V, K, S>
@@ -122,10 +288,13 @@ where
     #[inline]
     pub fn with_hasher(hash_builder: S) -> Self {
         Self {
-            map: DashMap::with_hasher(hash_builder.clone()),
-            strings: DashMap::with_hasher(hash_builder),
+            map: DefaultKeyMap::with_capacity_and_shard_amount(0, default_shard_amount()),
+            hash_builder,
+            strings: SegmentedVec::new(),
             key: AtomicUsize::new(0),
+            key_batch_size: 1,
             arena: Mutex::new(Arena::new()),
+            __normalizer: PhantomData,
         }
     }
 
@@ -143,10 +312,105 @@ where
     #[inline]
     pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
         Self {
-            map: DashMap::with_capacity_and_hasher(capacity, hash_builder.clone()),
-            strings: DashMap::with_capacity_and_hasher(capacity, hash_builder),
+            map: DefaultKeyMap::with_capacity_and_shard_amount(capacity, default_shard_amount()),
+            hash_builder,
+            strings: SegmentedVec::with_capacity(capacity),
             key: AtomicUsize::new(0),
+            key_batch_size: 1,
             arena: Mutex::new(Arena::new()),
+            __normalizer: PhantomData,
+        }
+    }
+}
+
+impl<V, K, N> ThreadedRodeo<V, K, RandomState, N>
+where
+    V: Internable + ?Sized,
+    K: Key + Hash,
+    N: Normalizer<V>,
+{
+    /// Creates an empty ThreadedRodeo which will use the [`Normalizer`] `N` to decide which strings are the same
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{AsciiCaseInsensitive, Spur, ThreadedRodeo};
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur, _, AsciiCaseInsensitive> = ThreadedRodeo::with_normalizer();
+    /// let key = rodeo.get_or_intern("Hello");
+    ///
+    /// assert_eq!(Some(key), rodeo.get("HELLO"));
+    /// assert_eq!("Hello", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    #[inline]
+    pub fn with_normalizer() -> Self {
+        Self::with_capacity_hasher_and_normalizer(0, RandomState::new())
+    }
+}
+
+impl<V, K, S, N> ThreadedRodeo<V, K, S, N>
+where
+    V: Internable + ?Sized,
+    K: Key + Hash,
+    S: BuildHasher + Clone,
+    N: Normalizer<V>,
+{
+    /// Creates a new ThreadedRodeo with the specified capacity that will use the given hasher for its
+    /// internal hashmap and the [`Normalizer`] `N` to decide which strings are the same
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{AsciiLowercase, Spur, ThreadedRodeo};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur, _, AsciiLowercase> =
+    ///     ThreadedRodeo::with_capacity_hasher_and_normalizer(10, RandomState::new());
+    /// let key = rodeo.get_or_intern("Hello");
+    ///
+    /// assert_eq!(Some(key), rodeo.get("HELLO"));
+    /// assert_eq!("hello", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    #[inline]
+    pub fn with_capacity_hasher_and_normalizer(capacity: usize, hash_builder: S) -> Self {
+        Self::with_config_and_hasher(ThreadedRodeoConfig::new().capacity(capacity), hash_builder)
+    }
+
+    /// Creates a new ThreadedRodeo laid out by the given [`ThreadedRodeoConfig`] that will use the given hasher
+    /// for its internal hashmap and the [`Normalizer`] `N` to decide which strings are the same
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{AsciiLowercase, Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let config = ThreadedRodeoConfig::new().capacity(10).shard_amount(4).key_batch_size(16);
+    /// let rodeo: ThreadedRodeo<str, Spur, _, AsciiLowercase> =
+    ///     ThreadedRodeo::with_config_and_hasher(config, RandomState::new());
+    /// let key = rodeo.get_or_intern("Hello");
+    ///
+    /// assert_eq!(4, rodeo.shard_amount());
+    /// assert_eq!(16, rodeo.key_batch_size());
+    /// assert_eq!("hello", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`ThreadedRodeoConfig`]: crate::ThreadedRodeoConfig
+    /// [`Normalizer`]: crate::Normalizer
+    #[inline]
+    pub fn with_config_and_hasher(config: ThreadedRodeoConfig, hash_builder: S) -> Self {
+        Self {
+            map: DefaultKeyMap::with_capacity_and_shard_amount(config.capacity, config.shard_amount),
+            hash_builder,
+            strings: SegmentedVec::with_capacity(config.capacity),
+            key: AtomicUsize::new(0),
+            key_batch_size: config.key_batch_size,
+            arena: Mutex::new(Arena::new()),
+            __normalizer: PhantomData,
         }
     }
 
@@ -171,36 +435,13 @@ where
     #[inline]
     pub fn get_or_intern<T>(&self, val: T) -> K
     where
-        T: AsRef<V>,
+        T: AsInternable<V>,
     {
-        if let Some(key) = self.map.get(val.as_ref()) {
-            *key
-        } else {
-            let shard = self.map.determine_map(val.as_ref());
-            // Safety: The indices provided by DashMap always refer to a shard in it's shards
-            let shard = unsafe { self.map.shards().get_unchecked(shard) };
+        let hash = self
+            .hash_builder
+            .hash_one(Normalized::<V, N>::new(val.as_internable()));
 
-            if let Some(key) = shard.read().get(val.as_ref()) {
-                return *key.get();
-            }
-
-            // Safety: The drop impl removes all references before the arena is dropped
-            let string: &'static V = unsafe {
-                V::from_raw(
-                    self.arena
-                        .lock()
-                        .unwrap()
-                        .store_slice(val.as_ref().to_raw()),
-                )
-            };
-            let key = K::try_from_usize(self.key.fetch_add(1, Ordering::SeqCst))
-                .expect("Failed to get or intern string");
-
-            self.map.insert(string, key);
-            self.strings.insert(key, string);
-
-            key
-        }
+        self.get_or_intern_with_hash(val, hash)
     }
 
     /// Get the key for a string, interning it if it does not yet exist
@@ -224,39 +465,123 @@ where
     #[inline]
     pub fn try_get_or_intern<T>(&self, val: T) -> Option<K>
     where
-        T: AsRef<V>,
+        T: AsInternable<V>,
     {
-        if let Some(key) = self.map.get(val.as_ref()) {
-            Some(*key)
-        } else {
-            let shard = self.map.determine_map(val.as_ref());
-            // Safety: The indices provided by DashMap always refer to a shard in it's shards
-            let shard = unsafe { self.map.shards().get_unchecked(shard) };
+        let hash = self
+            .hash_builder
+            .hash_one(Normalized::<V, N>::new(val.as_internable()));
 
-            if let Some(key) = shard.read().get(val.as_ref()) {
-                return Some(*key.get());
-            }
+        self.try_get_or_intern_with_hash(val, hash)
+    }
 
-            // Safety: The drop impl removes all references before the arena is dropped
-            let string: &'static V = unsafe {
-                V::from_raw(
-                    self.arena
-                        .lock()
-                        .unwrap()
-                        .store_slice(val.as_ref().to_raw()),
-                )
-            };
-            let key = K::try_from_usize(self.key.fetch_add(1, Ordering::SeqCst))?;
-
-            self.map.insert(string, key);
-            self.strings.insert(key, string);
-
-            Some(key)
+    /// Get the key for a string using a hash that was already computed with [`hash_one`],
+    /// interning it if it does not yet exist
+    ///
+    /// The hash is used to pick the map's shard and the string's place within it. Giving a hash that doesn't match
+    /// the string won't cause unsafety, but can cause duplicate keys and failed lookups
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key's `try_from_usize` function fails. With the default keys, this means that
+    /// you've interned more strings than it can handle. (For [`Spur`] this means that `u32::MAX - 1`
+    /// unique strings were interned)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::ThreadedRodeo;
+    ///
+    /// let rodeo = ThreadedRodeo::default();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    ///
+    /// let key = rodeo.get_or_intern_with_hash("Strings of things with wings and dings", hash);
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve(&key));
+    /// assert_eq!(hash, rodeo.hash_of(&key));
+    /// ```
+    ///
+    /// [`hash_one`]: ThreadedRodeo::hash_one
+    /// [`Spur`]: crate::Spur
+    #[inline]
+    pub fn get_or_intern_with_hash<T>(&self, val: T, hash: u64) -> K
+    where
+        T: AsInternable<V>,
+    {
+        self.try_get_or_intern_with_hash(val, hash)
+            .expect("Failed to get or intern string")
+    }
+
+    /// Get the key for a string using a hash that was already computed with [`hash_one`],
+    /// interning it if it does not yet exist
+    ///
+    /// Returns `None` if the key's `try_from_usize` function fails or the string can't be stored.
+    /// See [`get_or_intern_with_hash`] for how the hash is used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::ThreadedRodeo;
+    ///
+    /// let rodeo = ThreadedRodeo::default();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    ///
+    /// let key = rodeo.try_get_or_intern_with_hash("Strings of things with wings and dings", hash).unwrap();
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`hash_one`]: ThreadedRodeo::hash_one
+    /// [`get_or_intern_with_hash`]: ThreadedRodeo::get_or_intern_with_hash
+    #[inline]
+    pub fn try_get_or_intern_with_hash<T>(&self, val: T, hash: u64) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        let val = val.as_internable();
+        let normalized = Normalized::<V, N>::new(val);
+
+        if let Some(key) = self.find(normalized, hash) {
+            return Some(key);
         }
+
+        // The string is stored before the shard is locked for writing so that the arena's lock is never held
+        // along with a shard's, leaving the copy unused if another thread interns the string in the meantime
+        // Safety: The string is only referenced by the table of strings, which is dropped before the arena
+        let string: &'static V = unsafe {
+            V::from_raw(N::canonicalize(val, |canonical| {
+                self.arena.lock().unwrap().store_slice(canonical.to_raw())
+            }))
+        };
+
+        // Another thread may intern the string once the shard's read lock is released, so it's looked up
+        // again while the shard is locked for writing
+        self.map.get_or_insert_with(
+            hash,
+            |idx| self.is_match(idx, normalized),
+            |block, shard_len| {
+                // Each shard only touches the shared counter once it's used up the indices it reserved, and never
+                // reserves more indices than it has keys so that at most about as many indices go unused
+                if Range::is_empty(block) {
+                    let batch_size = self.key_batch_size.min(shard_len + 1);
+                    let start = self.key.fetch_add(batch_size, Ordering::SeqCst);
+                    *block = start..start.saturating_add(batch_size);
+                }
+                let key = K::try_from_usize(block.next()?)?;
+
+                // The string is published to the table before the key is added to the map, so any thread
+                // that finds the key is able to resolve it
+                // Safety: Each index is only reserved once, so nothing else writes it
+                unsafe { self.strings.write(key.into_usize(), (string, hash)) };
+
+                Some(key)
+            },
+            |key| self.hash_of(key),
+        )
     }
 
     /// Get the key value of a string, returning `None` if it doesn't exist
     ///
+    /// With keys that hold short strings within themselves like [`InlineSpur`], a key is returned for
+    /// **every** short string, including ones that were never interned
+    ///
     /// # Example
     ///
     /// ```rust
@@ -270,12 +595,88 @@ where
     /// assert_eq!(None, rodeo.get("This string isn't interned"));
     /// ```
     ///
+    /// [`InlineSpur`]: crate::InlineSpur
     #[inline]
     pub fn get<T>(&self, val: T) -> Option<K>
     where
-        T: AsRef<V>,
+        T: AsInternable<V>,
     {
-        self.map.get(val.as_ref()).map(|k| *k)
+        let normalized = Normalized::<V, N>::new(val.as_internable());
+
+        self.find(normalized, self.hash_builder.hash_one(normalized))
+    }
+
+    /// Get the key value of a string using a hash that was already computed with [`hash_one`],
+    /// returning `None` if it doesn't exist
+    ///
+    /// The hash is used to pick the map's shard and the string's place within it. Giving a hash that doesn't match
+    /// the string won't cause unsafety, but may fail to find the string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::ThreadedRodeo;
+    ///
+    /// let rodeo = ThreadedRodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    /// assert_eq!(Some(key), rodeo.get_with_hash("Strings of things with wings and dings", hash));
+    /// ```
+    ///
+    /// [`hash_one`]: ThreadedRodeo::hash_one
+    #[inline]
+    pub fn get_with_hash<T>(&self, val: T, hash: u64) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        self.find(Normalized::new(val.as_internable()), hash)
+    }
+
+    /// Hashes a string with the ThreadedRodeo's hasher and [`Normalizer`], giving the hash
+    /// that [`get_or_intern_with_hash`] and [`get_with_hash`] expect
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::ThreadedRodeo;
+    ///
+    /// let rodeo = ThreadedRodeo::default();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    ///
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    /// assert_eq!(hash, rodeo.hash_of(&key));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    /// [`get_or_intern_with_hash`]: ThreadedRodeo::get_or_intern_with_hash
+    /// [`get_with_hash`]: ThreadedRodeo::get_with_hash
+    #[inline]
+    pub fn hash_one<T>(&self, val: T) -> u64
+    where
+        T: AsInternable<V>,
+    {
+        self.hash_builder
+            .hash_one(Normalized::<V, N>::new(val.as_internable()))
+    }
+
+    /// Finds the key of a string by its hash
+    #[inline]
+    fn find(&self, val: &Normalized<V, N>, hash: u64) -> Option<K> {
+        if let Some(key) = inline_key(val, false) {
+            return Some(key);
+        }
+
+        self.map.get(hash, |idx| self.is_match(idx, val))
+    }
+
+    /// Returns `true` if the string of a key's index is the same as the given string
+    #[inline]
+    fn is_match(&self, idx: usize, val: &Normalized<V, N>) -> bool {
+        // Every key in the map is published to the table before it's added to the map
+        self.strings
+            .get(idx)
+            .is_some_and(|(string, _)| Normalized::new(string) == val)
     }
 
     /// Resolves a string by its key. Only keys made by the current ThreadedRodeo may be used
@@ -297,7 +698,7 @@ where
     ///
     #[inline]
     pub fn resolve<'a>(&'a self, key: &K) -> &'a V {
-        &*self.strings.get(key).expect("Key out of bounds")
+        self.try_resolve(key).expect("Key out of bounds")
     }
 
     /// Resolves a string by its key, returning `None` if it is out of bounds. Only keys made by the current
@@ -316,11 +717,53 @@ where
     ///
     #[inline]
     pub fn try_resolve<'a>(&'a self, key: &K) -> Option<&'a V> {
-        self.strings.get(key).map(|s| *s)
+        // Safety: The index is bounds checked, so a fabricated key can only fail to be found
+        self.strings
+            .get(unsafe { key.into_usize() })
+            .map(|(string, _)| string)
+    }
+
+    /// Gets the hash of a string by its key, which is the same hash the ThreadedRodeo's map uses.
+    /// Only keys made by the current ThreadedRodeo may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo};
+    /// use std::{collections::hash_map::RandomState, hash::BuildHasher};
+    ///
+    /// let hasher = RandomState::new();
+    /// let rodeo: ThreadedRodeo<str, Spur, RandomState> = ThreadedRodeo::with_hasher(hasher.clone());
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// assert_eq!(
+    ///     hasher.hash_one("Strings of things with wings and dings"),
+    ///     rodeo.hash_of(&key),
+    /// );
+    /// ```
+    ///
+    #[inline]
+    pub fn hash_of(&self, key: &K) -> u64 {
+        if let Some(string) = key.as_inline() {
+            return self.hash_builder.hash_one(Normalized::<V, N>::new(string));
+        }
+
+        // Safety: The index is bounds checked, so a fabricated key can only fail to be found
+        self.strings
+            .get(unsafe { key.into_usize() })
+            .expect("Key out of bounds")
+            .1
     }
 
     /// Gets the number of interned strings
     ///
+    /// Keys reserved in batches aren't dense, so the strings of a ThreadedRodeo with a
+    /// [`key_batch_size`] above one are counted by locking every shard of its map in turn
+    ///
     /// # Example
     ///
     /// ```rust
@@ -332,9 +775,14 @@ where
     /// assert_eq!(rodeo.len(), 1);
     /// ```
     ///
+    /// [`key_batch_size`]: crate::ThreadedRodeoConfig::key_batch_size
     #[inline]
     pub fn len(&self) -> usize {
-        self.strings.len()
+        if self.key_batch_size == 1 {
+            self.strings.len()
+        } else {
+            self.map.len()
+        }
     }
 
     /// Returns `true` if there are no currently interned strings
@@ -357,12 +805,11 @@ where
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// # // Note: The capacity of DashMap isn't reliable
+    /// ```rust
     /// use lasso::{Spur, ThreadedRodeo};
     ///
     /// let rodeo: ThreadedRodeo<str, Spur> = ThreadedRodeo::with_capacity(10);
-    /// assert_eq!(rodeo.capacity(), 10);
+    /// assert!(rodeo.capacity() >= 10);
     /// ```
     ///
     #[inline]
@@ -370,9 +817,53 @@ where
         self.strings.capacity()
     }
 
+    /// Returns the number of shards the ThreadedRodeo's map is split into
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur> =
+    ///     ThreadedRodeo::with_config(ThreadedRodeoConfig::new().shard_amount(64));
+    /// assert_eq!(rodeo.shard_amount(), 64);
+    /// ```
+    ///
+    #[inline]
+    pub fn shard_amount(&self) -> usize {
+        self.map.shard_amount()
+    }
+
+    /// Returns the number of keys each shard of the ThreadedRodeo's map reserves at once, where keys are
+    /// only dense if it's one
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    ///
+    /// assert_eq!(ThreadedRodeo::<str, Spur>::new().key_batch_size(), 1);
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur> =
+    ///     ThreadedRodeo::with_config(ThreadedRodeoConfig::new().key_batch_size(32));
+    /// assert_eq!(rodeo.key_batch_size(), 32);
+    /// ```
+    ///
+    #[inline]
+    pub fn key_batch_size(&self) -> usize {
+        self.key_batch_size
+    }
+
     /// Consumes the current ThreadedRodeo, returning a [`RodeoReader`] to allow contention-free access of the interner
     /// from multiple threads
     ///
+    /// The reader takes over the arena the strings are stored in instead of copying them, and
+    /// [`RodeoReader::share_suffixes`] can pack them into a single buffer afterwards
+    ///
+    /// The reader's keys are always dense, so if the ThreadedRodeo reserves keys in batches and some of them went
+    /// unused, strings get new keys that the current ones don't resolve to. Use [`into_reader_with_remap`] to
+    /// translate the current keys in that case
+    ///
     /// # Example
     ///
     /// ```rust
@@ -389,46 +880,100 @@ where
     /// ```
     ///
     /// [`RodeoReader`]: crate::RodeoReader
+    /// [`RodeoReader::share_suffixes`]: crate::RodeoReader::share_suffixes
+    /// [`into_reader_with_remap`]: ThreadedRodeo::into_reader_with_remap
     #[inline]
     #[must_use]
-    pub fn into_reader(self) -> RodeoReader<V, K, S> {
-        // Take the strings vec from the old lasso
-        let mut strings = iter::from_fn(|| Some(None))
-            .take(self.strings.len())
-            .collect::<Vec<Option<&'static V>>>();
-
-        for shard in self.strings.shards() {
-            for (key, val) in shard.write().drain() {
-                // Safety: The keys of the dashmap should be valid indices
-                unsafe {
-                    // TODO: get_unchecked?
-                    strings[key.into_usize()] = Some(val.into_inner());
-                }
-            }
-        }
+    pub fn into_reader(self) -> RodeoReader<V, K, S, N> {
+        let (reader, _) = self.into_reader_with_remap();
 
-        // Drain the DashMap by draining each of its buckets and creating a new hashmap to store their values
-        let mut map: HashMap<&'static V, K, S> =
-            HashMap::with_capacity_and_hasher(strings.len(), self.map.hasher().clone());
+        reader
+    }
 
-        for shard in self.map.shards() {
-            // Extend the new map by the contents of the shard
-            map.extend(shard.write().drain().map(|(k, v)| (k, v.into_inner())));
-        }
+    /// Consumes the current ThreadedRodeo, returning a [`RodeoReader`] along with the [`KeyRemap`] from the current
+    /// keys to the reader's keys if they're different, which is only when the ThreadedRodeo reserves keys in batches
+    /// and some of them weren't used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur> =
+    ///     ThreadedRodeo::with_config(ThreadedRodeoConfig::new().key_batch_size(16));
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    ///
+    /// let (rodeo_reader, remap) = rodeo.into_reader_with_remap();
+    /// let key = remap.map_or(key, |remap| remap.remap(&key));
+    /// assert_eq!(
+    ///     "Appear weak when you are strong, and strong when you are weak.",
+    ///     rodeo_reader.resolve(&key),
+    /// );
+    /// ```
+    ///
+    /// [`RodeoReader`]: crate::RodeoReader
+    /// [`KeyRemap`]: crate::KeyRemap
+    #[inline]
+    #[must_use]
+    pub fn into_reader_with_remap(self) -> (RodeoReader<V, K, S, N>, Option<KeyRemap<K>>) {
+        let (entries, remap) = self.compact();
+        let (strings, hashes): (Vec<&'static V>, Vec<u64>) = entries.into_iter().unzip();
 
-        // Safety: No other references outside of `map` and `strings` to the interned strings exist
-        unsafe {
-            RodeoReader::new(
-                map,
-                strings.into_iter().map(|s| s.unwrap()).collect(),
-                mem::take(&mut *self.arena.lock().unwrap()),
-            )
-        }
+        // The reader's map only holds keys, so it's filled from scratch using the cached hashes of each string
+        self.map.clear();
+        let map = key_map(&hashes, self.hash_builder.clone());
+
+        // Safety: Every string is stored in the arena, which the table takes along with them
+        let table =
+            unsafe { StringTable::from_arena(strings, mem::take(&mut *self.arena.lock().unwrap())) };
+
+        (RodeoReader::new(map, table, hashes), remap)
+    }
+
+    /// Consumes the current ThreadedRodeo, returning a [`RodeoReader`] with a bloom filter that rejects most lookups
+    /// of strings that aren't interned, see [`RodeoReader::build_bloom_filter`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` isn't between zero and one exclusive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::ThreadedRodeo;
+    ///
+    /// let rodeo = ThreadedRodeo::default();
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    ///
+    /// let read_only_rodeo = rodeo.into_reader_with_bloom_filter(0.01);
+    /// assert!(read_only_rodeo.has_bloom_filter());
+    /// assert_eq!(
+    ///     Some(key),
+    ///     read_only_rodeo.get("Appear weak when you are strong, and strong when you are weak."),
+    /// );
+    /// ```
+    ///
+    /// [`RodeoReader`]: crate::RodeoReader
+    /// [`RodeoReader::build_bloom_filter`]: crate::RodeoReader::build_bloom_filter
+    #[inline]
+    #[must_use]
+    pub fn into_reader_with_bloom_filter(self, false_positive_rate: f64) -> RodeoReader<V, K, S, N> {
+        let mut reader = self.into_reader();
+        reader.build_bloom_filter(false_positive_rate);
+
+        reader
     }
 
     /// Consumes the current ThreadedRodeo, returning a [`RodeoResolver`] to allow contention-free access of the interner
     /// from multiple threads with the lowest possible memory consumption
     ///
+    /// The resolver takes over the arena the strings are stored in instead of copying them, and
+    /// [`RodeoResolver::share_suffixes`] can pack them into a single buffer afterwards
+    ///
+    /// The resolver's keys are always dense, so if the ThreadedRodeo reserves keys in batches and some of them went
+    /// unused, strings get new keys that the current ones don't resolve to. Use [`into_resolver_with_remap`] to
+    /// translate the current keys in that case
+    ///
     /// # Example
     ///
     /// ```rust
@@ -445,32 +990,154 @@ where
     /// ```
     ///
     /// [`RodeoResolver`]: crate::RodeoResolver
+    /// [`RodeoResolver::share_suffixes`]: crate::RodeoResolver::share_suffixes
+    /// [`into_resolver_with_remap`]: ThreadedRodeo::into_resolver_with_remap
     #[inline]
     #[must_use]
     pub fn into_resolver(self) -> RodeoResolver<V, K> {
+        let (resolver, _) = self.into_resolver_with_remap();
+
+        resolver
+    }
+
+    /// Consumes the current ThreadedRodeo, returning a [`RodeoResolver`] along with the [`KeyRemap`] from the current
+    /// keys to the resolver's keys if they're different, which is only when the ThreadedRodeo reserves keys in batches
+    /// and some of them weren't used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, ThreadedRodeo, ThreadedRodeoConfig};
+    ///
+    /// let rodeo: ThreadedRodeo<str, Spur> =
+    ///     ThreadedRodeo::with_config(ThreadedRodeoConfig::new().key_batch_size(16));
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    ///
+    /// let (rodeo_resolver, remap) = rodeo.into_resolver_with_remap();
+    /// let key = remap.map_or(key, |remap| remap.remap(&key));
+    /// assert_eq!(
+    ///     "Appear weak when you are strong, and strong when you are weak.",
+    ///     rodeo_resolver.resolve(&key),
+    /// );
+    /// ```
+    ///
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    /// [`KeyRemap`]: crate::KeyRemap
+    #[inline]
+    #[must_use]
+    pub fn into_resolver_with_remap(self) -> (RodeoResolver<V, K>, Option<KeyRemap<K>>) {
+        let (entries, remap) = self.compact();
         self.map.clear();
 
-        let mut strings = iter::from_fn(|| Some(None))
-            .take(self.strings.len())
-            .collect::<Vec<Option<&'static V>>>();
+        let strings: Vec<&'static V> = entries.into_iter().map(|(string, _)| string).collect();
 
-        for shard in self.strings.shards() {
-            for (key, val) in shard.write().drain() {
-                // Safety: The keys of the dashmap should be valid indices
-                unsafe {
-                    // TODO: get_unchecked?
-                    strings[key.into_usize()] = Some(val.into_inner());
-                }
+        // Safety: Every string is stored in the arena, which the table takes along with them
+        let table =
+            unsafe { StringTable::from_arena(strings, mem::take(&mut *self.arena.lock().unwrap())) };
+        let resolver = RodeoResolver::new(table);
+
+        (resolver, remap)
+    }
+
+    /// Copies every string and its hash out of the table in order of their keys, along with a [`KeyRemap`]
+    /// to their indices if there are gaps between the keys
+    ///
+    /// Must be called before the map is cleared, since the buffers are sized by the number of keys in it
+    #[inline]
+    fn compact(&self) -> (Vec<(&'static V, u64)>, Option<KeyRemap<K>>) {
+        let len = self.len();
+        let mut entries = Vec::with_capacity(len);
+        let mut keys = Vec::with_capacity(len);
+
+        // Indices past the last valid key can be reserved, but are never written
+        let reserved = self.key.load(Ordering::SeqCst);
+        for idx in (0..reserved).take_while(|&idx| K::try_from_usize(idx).is_some()) {
+            if let Some(entry) = self.strings.get(idx) {
+                entries.push(entry);
+                keys.push(K::try_from_usize(idx).unwrap_or_else(|| unreachable!()));
             }
         }
 
-        // Safety: No other references to the strings exist
-        unsafe {
-            RodeoResolver::new(
-                strings.into_iter().map(|s| s.unwrap()).collect(),
-                mem::take(&mut *self.arena.lock().unwrap()),
-            )
-        }
+        // The keys have no gaps if the last one's index is the number of keys before it
+        // Safety: The key was just made from its index
+        let remap = match keys.last() {
+            Some(&last) if unsafe { last.into_usize() } + 1 != keys.len() => {
+                Some(KeyRemap::with_gaps(keys))
+            }
+            _ => None,
+        };
+
+        (entries, remap)
+    }
+}
+
+impl<K, S, N> ThreadedRodeo<str, K, S, N>
+where
+    K: Key + Hash,
+    S: BuildHasher + Clone,
+    N: Normalizer<str>,
+{
+    /// Resolves a string by its key into the given buffer, replacing its contents. Unlike [`resolve`], this
+    /// also resolves keys that hold their string within themselves, see [`InlineSpur`]. Only keys made by the
+    /// current ThreadedRodeo may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, ThreadedRodeo};
+    ///
+    /// let rodeo: ThreadedRodeo<str, InlineSpur> = ThreadedRodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let mut buf = String::new();
+    /// assert_eq!("+", rodeo.resolve_into(&plus, &mut buf));
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve_into(&long, &mut buf));
+    /// ```
+    ///
+    /// [`resolve`]: ThreadedRodeo::resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> &'b str {
+        self.try_resolve_into(key, buf).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents, or returns `None` and
+    /// leaves the buffer alone if the key is out of bounds. Unlike [`try_resolve`], this also resolves keys
+    /// that hold their string within themselves, see [`InlineSpur`]. Only keys made by the current
+    /// ThreadedRodeo may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key, ThreadedRodeo};
+    ///
+    /// let rodeo: ThreadedRodeo<str, InlineSpur> = ThreadedRodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let mut buf = String::new();
+    /// assert_eq!(Some("+"), rodeo.try_resolve_into(&plus, &mut buf));
+    /// assert_eq!(None, rodeo.try_resolve_into(&InlineSpur::try_from_usize(10).unwrap(), &mut buf));
+    /// ```
+    ///
+    /// [`try_resolve`]: ThreadedRodeo::try_resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn try_resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> Option<&'b str> {
+        let string = match key.as_inline::<str>() {
+            Some(string) => string,
+            None => self.try_resolve(key)?,
+        };
+
+        buf.clear();
+        buf.push_str(string);
+
+        Some(buf.as_str())
     }
 }
 
@@ -485,45 +1152,28 @@ impl Default for ThreadedRodeo<str, Spur, RandomState> {
     }
 }
 
-/// Deallocate the leaked strings interned by ThreadedRodeo
-impl<V, K, S> Drop for ThreadedRodeo<V, K, S>
-where
-    V: Internable + ?Sized,
-    K: Key + Hash,
-    S: BuildHasher + Clone,
-{
-    #[inline]
-    fn drop(&mut self) {
-        // Clear the map to remove all other references to the strings in self.strings
-        self.map.clear();
-
-        // Drain self.strings while deallocating the strings it holds
-        for shard in self.strings.shards() {
-            shard.write().drain().for_each(drop);
-        }
-    }
-}
-
-unsafe impl<V, K, S> Sync for ThreadedRodeo<V, K, S>
+unsafe impl<V, K, S, N> Sync for ThreadedRodeo<V, K, S, N>
 where
     V: Internable + ?Sized + Sync,
     K: Key + Hash + Sync,
     S: BuildHasher + Clone + Sync,
+    N: Normalizer<V>,
 {
 }
 
-unsafe impl<V, K, S> Send for ThreadedRodeo<V, K, S>
+unsafe impl<V, K, S, N> Send for ThreadedRodeo<V, K, S, N>
 where
     V: Internable + ?Sized + Send,
     K: Key + Hash + Send,
     S: BuildHasher + Clone + Send,
+    N: Normalizer<V>,
 {
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{hasher::RandomState, MicroSpur};
+    use crate::{hasher::RandomState, InlineSpur, MicroSpur};
 
     #[cfg(not(any(miri, feature = "no-std")))]
     use std::{sync::Arc, thread};
@@ -541,12 +1191,11 @@ mod tests {
         let _: ThreadedRodeo<str, Spur> = ThreadedRodeo::new();
     }
 
-    // Capacity of DashMap isn't reliable
-    // #[test]
-    // fn with_capacity() {
-    //     let rodeo: ThreadedRodeo<Spur> = ThreadedRodeo::with_capacity(10);
-    //     assert_eq!(10, rodeo.capacity());
-    // }
+    #[test]
+    fn with_capacity() {
+        let rodeo: ThreadedRodeo<str, Spur> = ThreadedRodeo::with_capacity(10);
+        assert!(rodeo.capacity() >= 10);
+    }
 
     #[test]
     fn with_hasher() {
@@ -611,7 +1260,7 @@ mod tests {
     fn try_get_or_intern() {
         let rodeo: ThreadedRodeo<str, MicroSpur> = ThreadedRodeo::new();
 
-        for i in 0..u8::max_value() as usize - 1 {
+        for i in 0..u8::MAX as usize - 1 {
             rodeo.get_or_intern(i.to_string());
         }
 
@@ -626,7 +1275,7 @@ mod tests {
     fn try_get_or_intern_threaded() {
         let rodeo: Arc<ThreadedRodeo<str, MicroSpur>> = Arc::new(ThreadedRodeo::new());
 
-        for i in 0..u8::max_value() as usize - 1 {
+        for i in 0..u8::MAX as usize - 1 {
             rodeo.get_or_intern(i.to_string());
         }
 
@@ -762,20 +1411,215 @@ mod tests {
         assert!(rodeo.is_empty());
     }
 
-    // #[test]
-    // fn clone() {
-    //     let rodeo = ThreadedRodeo::default();
-    //     let key = rodeo.get_or_intern("Test");
-    //
-    //     assert_eq!("Test", rodeo.resolve(&key));
-    //
-    //     let cloned = rodeo.clone();
-    //     assert_eq!("Test", cloned.resolve(&key));
-    //
-    //     drop(rodeo);
-    //
-    //     assert_eq!("Test", cloned.resolve(&key));
-    // }
+    #[test]
+    fn shard_amount() {
+        assert!(ThreadedRodeo::default().shard_amount().is_power_of_two());
+
+        for &amount in &[1, 2, 256] {
+            let rodeo: ThreadedRodeo = ThreadedRodeo::with_config(ThreadedRodeoConfig::new().shard_amount(amount));
+            assert_eq!(amount, rodeo.shard_amount());
+
+            let keys = (0..100)
+                .map(|i| rodeo.get_or_intern(i.to_string()))
+                .collect::<Vec<_>>();
+            for (i, key) in keys.iter().enumerate() {
+                assert_eq!(Some(*key), rodeo.get(i.to_string()));
+                assert_eq!(i.to_string(), rodeo.resolve(key));
+            }
+        }
+    }
+
+    #[test]
+    fn batched_keys() {
+        let config = ThreadedRodeoConfig::new().shard_amount(4).key_batch_size(1000);
+        let rodeo: ThreadedRodeo = ThreadedRodeo::with_config(config);
+
+        let keys = (0..100)
+            .map(|i| rodeo.get_or_intern(i.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(100, rodeo.len());
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(Some(*key), rodeo.get(i.to_string()));
+            assert_eq!(i.to_string(), rodeo.resolve(key));
+        }
+
+        // Each shard reserves its own block, so the keys skip over the rest of every block
+        assert!(keys
+            .iter()
+            .any(|key| unsafe { key.into_usize() } >= rodeo.len()));
+
+        // Shards reserve no more keys than they hold plus one, however large the batches are
+        assert!(rodeo.key.load(Ordering::SeqCst) <= 2 * 100 + 4);
+        assert!(rodeo.strings.capacity() < 1000);
+    }
+
+    #[test]
+    fn batched_keys_into_reader() {
+        let config = ThreadedRodeoConfig::new().shard_amount(4).key_batch_size(1000);
+        let rodeo: ThreadedRodeo = ThreadedRodeo::with_config(config);
+        let keys = (0..100)
+            .map(|i| rodeo.get_or_intern(i.to_string()))
+            .collect::<Vec<_>>();
+
+        let (reader, remap) = rodeo.into_reader_with_remap();
+        let remap = remap.unwrap();
+        assert_eq!(100, reader.len());
+        assert_eq!(100, remap.len());
+        for (i, key) in keys.iter().enumerate() {
+            let key = remap.remap(key);
+
+            assert!(unsafe { key.into_usize() } < 100);
+            assert_eq!(i.to_string(), reader.resolve(&key));
+            assert_eq!(Some(key), reader.get(i.to_string()));
+        }
+
+        assert_eq!(100, reader.into_resolver().len());
+    }
+
+    #[test]
+    fn batched_keys_into_resolver() {
+        let config = ThreadedRodeoConfig::new().shard_amount(4).key_batch_size(1000);
+        let rodeo: ThreadedRodeo = ThreadedRodeo::with_config(config);
+        let keys = (0..100)
+            .map(|i| rodeo.get_or_intern(i.to_string()))
+            .collect::<Vec<_>>();
+
+        let (resolver, remap) = rodeo.into_resolver_with_remap();
+        let remap = remap.unwrap();
+        assert_eq!(100, resolver.len());
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(i.to_string(), resolver.resolve(&remap.remap(key)));
+        }
+    }
+
+    #[test]
+    fn batched_keys_into_reader_compacts() {
+        let config = ThreadedRodeoConfig::new().shard_amount(4).key_batch_size(1000);
+        let rodeo: ThreadedRodeo = ThreadedRodeo::with_config(config);
+        for i in 0..100 {
+            rodeo.get_or_intern(i.to_string());
+        }
+
+        let reader = rodeo.into_reader();
+        assert_eq!(100, reader.len());
+        for i in 0..100 {
+            let key = reader.get(i.to_string()).unwrap();
+
+            assert!(unsafe { key.into_usize() } < 100);
+            assert_eq!(i.to_string(), reader.resolve(&key));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn invalid_shard_amount_over_max() {
+        let _ = ThreadedRodeoConfig::new().shard_amount(MAX_SHARD_AMOUNT * 2);
+    }
+
+    #[test]
+    fn unbatched_keys_have_no_remap() {
+        let rodeo = ThreadedRodeo::default();
+        let key = rodeo.get_or_intern("A");
+
+        let (reader, remap) = rodeo.into_reader_with_remap();
+        assert!(remap.is_none());
+        assert_eq!("A", reader.resolve(&key));
+    }
+
+    #[test]
+    #[cfg(not(any(miri, feature = "no-std")))]
+    fn batched_keys_threaded() {
+        let rodeo: Arc<ThreadedRodeo> = Arc::new(ThreadedRodeo::with_config(
+            ThreadedRodeoConfig::new().key_batch_size(16),
+        ));
+
+        let handles = (0..4)
+            .map(|thread| {
+                let rodeo = Arc::clone(&rodeo);
+                thread::spawn(move || {
+                    (0..500)
+                        .map(|i| {
+                            // Half of the strings are interned by every thread
+                            let string = if i % 2 == 0 {
+                                i.to_string()
+                            } else {
+                                format!("{}-{}", thread, i)
+                            };
+                            let key = rodeo.get_or_intern(&string);
+                            assert_eq!(string, rodeo.resolve(&key));
+
+                            (key, string)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let interned = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        let rodeo = Arc::try_unwrap(rodeo).ok().unwrap();
+        assert_eq!(250 + 4 * 250, rodeo.len());
+
+        let (reader, remap) = rodeo.into_reader_with_remap();
+        let remap = |key| remap.as_ref().map_or(key, |remap| remap.remap(&key));
+        assert_eq!(250 + 4 * 250, reader.len());
+        for (key, string) in interned {
+            assert_eq!(string, reader.resolve(&remap(key)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn invalid_key_batch_size() {
+        let _ = ThreadedRodeoConfig::new().key_batch_size(0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn invalid_shard_amount() {
+        let _ = ThreadedRodeoConfig::new().shard_amount(0);
+    }
+
+    #[test]
+    fn with_hash() {
+        let rodeo = ThreadedRodeo::default();
+        let hash = rodeo.hash_one("A");
+
+        assert_eq!(None, rodeo.get_with_hash("A", hash));
+
+        let a = rodeo.get_or_intern_with_hash("A", hash);
+        assert_eq!(a, rodeo.get_or_intern("A"));
+        assert_eq!(a, rodeo.get_or_intern_with_hash("A", hash));
+        assert_eq!(Some(a), rodeo.try_get_or_intern_with_hash("A", hash));
+        assert_eq!(Some(a), rodeo.get_with_hash("A", hash));
+        assert_eq!(Some(a), rodeo.get("A"));
+        assert_eq!(hash, rodeo.hash_of(&a));
+
+        let reader = rodeo.into_reader();
+        assert_eq!(hash, reader.hash_one("A"));
+        assert_eq!(Some(a), reader.get_with_hash("A", hash));
+    }
+
+    #[test]
+    fn hash_of() {
+        let rodeo = ThreadedRodeo::default();
+        let a = rodeo.get_or_intern("A");
+        let b = rodeo.get_or_intern("B");
+
+        let (hash_a, hash_b) = (rodeo.hash_of(&a), rodeo.hash_of(&b));
+        assert_ne!(hash_a, hash_b);
+
+        let reader = rodeo.into_reader();
+        assert_eq!(hash_a, reader.hash_of(&a));
+        assert_eq!(hash_b, reader.hash_of(&b));
+        assert_eq!(Some(a), reader.get("A"));
+        assert_eq!(Some(b), reader.get("B"));
+    }
 
     #[test]
     fn drops() {
@@ -793,6 +1637,28 @@ mod tests {
         });
     }
 
+    #[test]
+    fn inline_keys() {
+        let rodeo: ThreadedRodeo<str, InlineSpur> = ThreadedRodeo::new();
+        let plus = rodeo.get_or_intern("+");
+        let long = rodeo.try_get_or_intern("Hello").unwrap();
+
+        assert!(plus.is_inline());
+        assert!(!long.is_inline());
+        assert_eq!(1, rodeo.len());
+
+        assert_eq!(Some(plus), rodeo.get("+"));
+        assert_eq!(Some(long), rodeo.get("Hello"));
+        let mut buf = String::new();
+        assert_eq!(None, rodeo.try_resolve(&plus));
+        assert_eq!("+", rodeo.resolve_into(&plus, &mut buf));
+        assert_eq!(Some("Hello"), rodeo.try_resolve_into(&long, &mut buf));
+
+        let reader = rodeo.into_reader();
+        assert_eq!(Some(long), reader.get("Hello"));
+        assert_eq!("+", reader.resolve_into(&plus, &mut buf));
+    }
+
     #[test]
     #[cfg(not(any(miri, feature = "no-std")))]
     fn debug() {
@@ -817,4 +1683,111 @@ mod tests {
         let reader = rodeo.into_reader();
         assert_eq!("A", reader.resolve(&key));
     }
+    #[test]
+    #[cfg(not(any(miri, feature = "no-std")))]
+    fn conversions_threaded() {
+        let rodeo = Arc::new(ThreadedRodeo::default());
+
+        let handles = (0..4)
+            .map(|thread| {
+                let rodeo = Arc::clone(&rodeo);
+                thread::spawn(move || {
+                    (0..250)
+                        .map(|i| {
+                            let string = format!("{}-{}", thread, i);
+                            let key = rodeo.get_or_intern(&string);
+
+                            (key, string)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let interned = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        let rodeo = Arc::try_unwrap(rodeo).ok().unwrap();
+        let hashes = interned
+            .iter()
+            .map(|(key, _)| rodeo.hash_of(key))
+            .collect::<Vec<_>>();
+
+        let reader = rodeo.into_reader();
+        assert_eq!(reader.len(), interned.len());
+        for ((key, string), hash) in interned.iter().zip(hashes) {
+            assert_eq!(string, reader.resolve(key));
+            assert_eq!(Some(*key), reader.get(string));
+            assert_eq!(hash, reader.hash_of(key));
+        }
+
+        let resolver = reader.into_resolver();
+        for (key, string) in interned.iter() {
+            assert_eq!(string, resolver.resolve(key));
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(miri, feature = "no-std")))]
+    fn publication_threaded() {
+        use core::sync::atomic::AtomicBool;
+
+        const STRINGS: usize = 2000;
+
+        let rodeo = Arc::new(ThreadedRodeo::default());
+        let interning = Arc::new(AtomicBool::new(true));
+
+        // Every writer interns the same strings in a different order so that they race on each one
+        let writers = (0..4)
+            .map(|thread| {
+                let rodeo = Arc::clone(&rodeo);
+                thread::spawn(move || {
+                    for i in 0..STRINGS {
+                        let string = ((i * 7 + thread * 500) % STRINGS).to_string();
+                        let key = rodeo.get_or_intern(&string);
+
+                        assert_eq!(string, rodeo.resolve(&key));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let readers = (0..4)
+            .map(|_| {
+                let rodeo = Arc::clone(&rodeo);
+                let interning = Arc::clone(&interning);
+                thread::spawn(move || {
+                    while interning.load(Ordering::Acquire) {
+                        for i in 0..STRINGS {
+                            let string = i.to_string();
+                            if let Some(key) = rodeo.get(&string) {
+                                assert_eq!(string, rodeo.resolve(&key));
+                            }
+                        }
+
+                        let len = rodeo.len();
+                        for idx in 0..len {
+                            rodeo.resolve(&Spur::try_from_usize(idx).unwrap());
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        writers
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+        interning.store(false, Ordering::Release);
+        readers
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+
+        // Racing writers all get the same key for a string instead of interning it more than once
+        assert_eq!(STRINGS, rodeo.len());
+        for i in 0..STRINGS {
+            let string = i.to_string();
+            assert_eq!(string, rodeo.resolve(&rodeo.get(&string).unwrap()));
+        }
+    }
 }
@@ -0,0 +1,284 @@
+use crate::{key::Key, table::StringTable};
+
+use core::{cmp, iter, mem};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::vec::{self, Vec};
+    } else {
+        use std::vec;
+    }
+}
+
+/// A BK-tree over interned strings, where every child is filed under its edit distance from its parent
+///
+/// Edit distance is a metric, so when searching for strings within `max` of a query that's `d` away from
+/// a node, only the node's children filed under distances in `d - max..=d + max` can hold matches
+#[derive(Debug, Clone)]
+pub(crate) struct BkTree<K: Key> {
+    /// All nodes of the tree, with the root first when there are any
+    nodes: Vec<BkNode<K>>,
+}
+
+#[derive(Debug, Clone)]
+struct BkNode<K: Key> {
+    key: K,
+    /// The distance of each child from this node along with the child's index, sorted by distance
+    children: Vec<(usize, usize)>,
+}
+
+impl<K: Key> BkTree<K> {
+    /// Creates the tree for the given strings, where each string's index is its key
+    #[inline]
+    pub(crate) fn new(strings: &StringTable<str>) -> Self {
+        assert!(
+            !K::can_inline::<str>(),
+            "Indices can't be built over keys that hold strings within themselves"
+        );
+
+        let mut nodes: Vec<BkNode<K>> = Vec::with_capacity(strings.len());
+        let mut distance = Levenshtein::default();
+
+        for (idx, string) in strings.iter().enumerate() {
+            let key = K::try_from_usize(idx).unwrap_or_else(|| unreachable!());
+
+            if !nodes.is_empty() {
+                distance.set_source(string);
+
+                let mut parent = 0;
+                loop {
+                    // Safety: Every node's key was created from an index into `strings`
+                    let dist = distance.to(unsafe { resolve(strings, &nodes[parent].key) });
+                    let children = &mut nodes[parent].children;
+
+                    match children.binary_search_by_key(&dist, |&(child_dist, _)| child_dist) {
+                        Ok(child) => parent = children[child].1,
+                        Err(position) => {
+                            children.insert(position, (dist, idx));
+                            break;
+                        }
+                    }
+                }
+            }
+
+            nodes.push(BkNode {
+                key,
+                children: Vec::new(),
+            });
+        }
+
+        nodes
+            .iter_mut()
+            .for_each(|node| node.children.shrink_to_fit());
+
+        Self { nodes }
+    }
+
+    /// Gets the keys of all strings within `max_distance` edits of `target` along with their distances,
+    /// sorted by distance and then key
+    ///
+    /// # Safety
+    ///
+    /// `strings` must be the same strings the tree was created with
+    ///
+    #[inline]
+    pub(crate) unsafe fn search(
+        &self,
+        strings: &StringTable<str>,
+        target: &str,
+        max_distance: usize,
+    ) -> Vec<(K, usize)> {
+        let mut found = Vec::new();
+        if self.nodes.is_empty() {
+            return found;
+        }
+
+        let mut distance = Levenshtein::default();
+        distance.set_source(target);
+
+        let mut stack = Vec::new();
+        stack.push(0);
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            let dist = distance.to(resolve(strings, &node.key));
+
+            if dist <= max_distance {
+                found.push((node.key, dist));
+            }
+
+            let low = dist.saturating_sub(max_distance);
+            let high = dist.saturating_add(max_distance);
+            let start = node
+                .children
+                .partition_point(|&(child_dist, _)| child_dist < low);
+
+            stack.extend(
+                node.children[start..]
+                    .iter()
+                    .take_while(|&&(child_dist, _)| child_dist <= high)
+                    .map(|&(_, child)| child),
+            );
+        }
+
+        found.sort_unstable_by_key(|&(key, dist)| (dist, key.into_usize()));
+        found
+    }
+}
+
+/// Resolves a key from the tree
+///
+/// # Safety
+///
+/// The key must be an index into `strings`
+///
+#[inline]
+unsafe fn resolve<'a, K: Key>(strings: &'a StringTable<str>, key: &K) -> &'a str {
+    strings.get_unchecked(key.into_usize())
+}
+
+/// Computes the Levenshtein distance between a fixed source string and other strings, counting
+/// insertions, deletions and substitutions of characters, while reusing its buffers
+#[derive(Debug, Default)]
+struct Levenshtein {
+    source: Vec<char>,
+    row: Vec<usize>,
+}
+
+impl Levenshtein {
+    #[inline]
+    fn set_source(&mut self, source: &str) {
+        self.source.clear();
+        self.source.extend(source.chars());
+    }
+
+    #[inline]
+    fn to(&mut self, target: &str) -> usize {
+        // `row[i]` holds the distance between the first `i` characters of the source and the part
+        // of the target that's been seen so far
+        self.row.clear();
+        self.row.extend(0..=self.source.len());
+
+        for (j, target_char) in target.chars().enumerate() {
+            // The distance between the empty prefix of the source and the target so far
+            let mut diagonal = mem::replace(&mut self.row[0], j + 1);
+
+            for (i, &source_char) in self.source.iter().enumerate() {
+                let substitution = diagonal + (source_char != target_char) as usize;
+                let insertion = self.row[i] + 1;
+                let deletion = self.row[i + 1] + 1;
+
+                diagonal = mem::replace(
+                    &mut self.row[i + 1],
+                    cmp::min(substitution, cmp::min(insertion, deletion)),
+                );
+            }
+        }
+
+        self.row[self.source.len()]
+    }
+}
+
+/// An iterator over the keys of strings near a target string along with their edit distances,
+/// ordered by distance and then key, created with `nearest`
+#[derive(Debug, Clone)]
+pub struct Nearest<K: Key> {
+    iter: vec::IntoIter<(K, usize)>,
+}
+
+impl<K: Key> Nearest<K> {
+    #[inline]
+    pub(crate) fn new(found: Vec<(K, usize)>) -> Self {
+        Self {
+            iter: found.into_iter(),
+        }
+    }
+}
+
+impl<K: Key> Iterator for Nearest<K> {
+    type Item = (K, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K: Key> DoubleEndedIterator for Nearest<K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<K: Key> ExactSizeIterator for Nearest<K> {}
+
+impl<K: Key> iter::FusedIterator for Nearest<K> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Spur;
+
+    fn levenshtein(source: &str, target: &str) -> usize {
+        let mut distance = Levenshtein::default();
+        distance.set_source(source);
+        distance.to(target)
+    }
+
+    fn search(strings: &[&str], target: &str, max_distance: usize) -> Vec<(usize, usize)> {
+        let strings = StringTable::new(strings, None);
+        let tree = BkTree::<Spur>::new(&strings);
+
+        unsafe { tree.search(&strings, target, max_distance) }
+            .into_iter()
+            .map(|(key, dist)| (unsafe { key.into_usize() }, dist))
+            .collect()
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(0, levenshtein("", ""));
+        assert_eq!(3, levenshtein("abc", ""));
+        assert_eq!(3, levenshtein("", "abc"));
+        assert_eq!(3, levenshtein("kitten", "sitting"));
+        assert_eq!(2, levenshtein("flaw", "lawn"));
+        assert_eq!(1, levenshtein("größe", "gröse"));
+        assert_eq!(1, levenshtein("ß", "s"));
+        assert_eq!(
+            levenshtein("sunday", "saturday"),
+            levenshtein("saturday", "sunday")
+        );
+    }
+
+    #[test]
+    fn matches_linear_search() {
+        let strings = [
+            "length", "lenght", "width", "height", "weight", "eight", "len", "", "lengths",
+            "strength", "length",
+        ];
+
+        for target in &["length", "widht", "", "heigth", "xyz", "strenght"] {
+            for max_distance in 0..=4 {
+                let mut expected = strings
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, string)| (idx, levenshtein(target, string)))
+                    .filter(|&(_, dist)| dist <= max_distance)
+                    .collect::<Vec<_>>();
+                expected.sort_by_key(|&(idx, dist)| (dist, idx));
+
+                assert_eq!(expected, search(&strings, target, max_distance));
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(search(&[], "a", 10).is_empty());
+    }
+}
@@ -0,0 +1,534 @@
+use crate::internable::Internable;
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Decides which values an interner treats as the same, allowing things like case-insensitive
+/// or Unicode-normalized interning
+///
+/// Normalizers are used as a type parameter of [`Rodeo`], [`ThreadedRodeo`] and [`RodeoReader`], given
+/// to them with `with_normalizer`, and are applied to every value given to `get_or_intern` and `get`. Their functions hash and compare
+/// values directly so that lookups never need to allocate a normalized copy, and `hash` must
+/// agree with `eq`: values that are equal must have equal hashes
+///
+/// When a value is interned for the first time [`canonicalize`] chooses the spelling that's stored
+/// and returned by `resolve`, which by default is the first spelling seen
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{Normalizer, Rodeo, Spur};
+/// use std::hash::Hasher;
+///
+/// /// Ignores all leading and trailing whitespace, storing the trimmed string
+/// struct Trimmed;
+///
+/// impl Normalizer<str> for Trimmed {
+///     fn hash<H: Hasher>(value: &str, state: &mut H) {
+///         state.write(value.trim().as_bytes());
+///         state.write_u8(0xFF);
+///     }
+///
+///     fn eq(left: &str, right: &str) -> bool {
+///         left.trim() == right.trim()
+///     }
+///
+///     fn canonicalize<F, R>(value: &str, store: F) -> R
+///     where
+///         F: FnOnce(&str) -> R,
+///     {
+///         store(value.trim())
+///     }
+/// }
+///
+/// let mut rodeo: Rodeo<str, Spur, _, Trimmed> = Rodeo::with_normalizer();
+/// let key = rodeo.get_or_intern("  hello ");
+///
+/// assert_eq!(key, rodeo.get_or_intern("hello\n"));
+/// assert_eq!(Some(key), rodeo.get("hello"));
+/// assert_eq!("hello", rodeo.resolve(&key));
+/// ```
+///
+/// [`Rodeo`]: crate::Rodeo
+/// [`ThreadedRodeo`]: crate::ThreadedRodeo
+/// [`RodeoReader`]: crate::RodeoReader
+/// [`canonicalize`]: Normalizer::canonicalize
+pub trait Normalizer<V: Internable + ?Sized>: 'static {
+    /// Feeds the normalized form of `value` into the given hasher
+    fn hash<H: Hasher>(value: &V, state: &mut H);
+
+    /// Returns `true` if the normalized forms of both values are equal
+    fn eq(left: &V, right: &V) -> bool;
+
+    /// Gives the spelling of `value` that should be stored to `store` when it's interned for the
+    /// first time, returning whatever `store` returns
+    ///
+    /// The spelling given to `store` must be equal to `value` according to [`eq`], by default
+    /// `value` itself is stored so the first spelling seen is the one that's kept
+    ///
+    /// [`eq`]: Normalizer::eq
+    #[inline]
+    fn canonicalize<F, R>(value: &V, store: F) -> R
+    where
+        F: FnOnce(&V) -> R,
+    {
+        store(value)
+    }
+}
+
+/// The default [`Normalizer`], which compares values exactly as they are
+///
+/// [`Normalizer`]: crate::Normalizer
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Identity;
+
+impl<V> Normalizer<V> for Identity
+where
+    V: Internable + ?Sized,
+{
+    #[inline]
+    fn hash<H: Hasher>(value: &V, state: &mut H) {
+        value.hash(state);
+    }
+
+    #[inline]
+    fn eq(left: &V, right: &V) -> bool {
+        left == right
+    }
+}
+
+/// A [`Normalizer`] that ignores ASCII case, storing the first spelling that's seen
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{AsciiCaseInsensitive, Rodeo, Spur};
+///
+/// let mut rodeo: Rodeo<str, Spur, _, AsciiCaseInsensitive> = Rodeo::with_normalizer();
+/// let key = rodeo.get_or_intern("SELECT");
+///
+/// assert_eq!(key, rodeo.get_or_intern("select"));
+/// assert_eq!(Some(key), rodeo.get("Select"));
+/// assert_eq!("SELECT", rodeo.resolve(&key));
+/// ```
+///
+/// [`Normalizer`]: crate::Normalizer
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AsciiCaseInsensitive;
+
+impl AsciiCaseInsensitive {
+    /// Hashes the lowercased bytes in fixed size chunks so that hashers with
+    /// per-write state see the same sequence of writes for equal values
+    #[inline]
+    fn hash_bytes<H: Hasher>(bytes: &[u8], state: &mut H) {
+        let mut buf = [0; 32];
+        for chunk in bytes.chunks(buf.len()) {
+            let lowered = &mut buf[..chunk.len()];
+            lowered.copy_from_slice(chunk);
+            lowered.make_ascii_lowercase();
+
+            state.write(lowered);
+        }
+
+        // Mirrors the hash impl of `str` to prevent prefix collisions
+        state.write_u8(0xFF);
+    }
+}
+
+impl Normalizer<str> for AsciiCaseInsensitive {
+    #[inline]
+    fn hash<H: Hasher>(value: &str, state: &mut H) {
+        Self::hash_bytes(value.as_bytes(), state);
+    }
+
+    #[inline]
+    fn eq(left: &str, right: &str) -> bool {
+        left.eq_ignore_ascii_case(right)
+    }
+}
+
+impl Normalizer<[u8]> for AsciiCaseInsensitive {
+    #[inline]
+    fn hash<H: Hasher>(value: &[u8], state: &mut H) {
+        Self::hash_bytes(value, state);
+    }
+
+    #[inline]
+    fn eq(left: &[u8], right: &[u8]) -> bool {
+        left.eq_ignore_ascii_case(right)
+    }
+}
+
+/// A [`Normalizer`] that ignores ASCII case, storing the ASCII lowercase form of the first
+/// spelling that's seen
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{AsciiLowercase, Rodeo, Spur};
+///
+/// let mut rodeo: Rodeo<str, Spur, _, AsciiLowercase> = Rodeo::with_normalizer();
+/// let key = rodeo.get_or_intern("SELECT");
+///
+/// assert_eq!(key, rodeo.get_or_intern("Select"));
+/// assert_eq!("select", rodeo.resolve(&key));
+/// ```
+///
+/// [`Normalizer`]: crate::Normalizer
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AsciiLowercase;
+
+impl Normalizer<str> for AsciiLowercase {
+    #[inline]
+    fn hash<H: Hasher>(value: &str, state: &mut H) {
+        <AsciiCaseInsensitive as Normalizer<str>>::hash(value, state);
+    }
+
+    #[inline]
+    fn eq(left: &str, right: &str) -> bool {
+        left.eq_ignore_ascii_case(right)
+    }
+
+    #[inline]
+    fn canonicalize<F, R>(value: &str, store: F) -> R
+    where
+        F: FnOnce(&str) -> R,
+    {
+        if value.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            store(&value.to_ascii_lowercase())
+        } else {
+            store(value)
+        }
+    }
+}
+
+impl Normalizer<[u8]> for AsciiLowercase {
+    #[inline]
+    fn hash<H: Hasher>(value: &[u8], state: &mut H) {
+        <AsciiCaseInsensitive as Normalizer<[u8]>>::hash(value, state);
+    }
+
+    #[inline]
+    fn eq(left: &[u8], right: &[u8]) -> bool {
+        left.eq_ignore_ascii_case(right)
+    }
+
+    #[inline]
+    fn canonicalize<F, R>(value: &[u8], store: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        if value.iter().any(|byte| byte.is_ascii_uppercase()) {
+            store(&value.to_ascii_lowercase())
+        } else {
+            store(value)
+        }
+    }
+}
+
+/// A [`Normalizer`] that ignores case using Unicode simple case folding, storing the first
+/// spelling that's seen
+///
+/// Each character is folded on its own, so characters that only have multi-character foldings
+/// like `ß` are compared exactly, while characters like `Σ`, `σ` and `ς` are all the same
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{Rodeo, SimpleCaseFold, Spur};
+///
+/// let mut rodeo: Rodeo<str, Spur, _, SimpleCaseFold> = Rodeo::with_normalizer();
+/// let key = rodeo.get_or_intern("Σίσυφος");
+///
+/// assert_eq!(Some(key), rodeo.get("ΣΊΣΥΦΟΣ"));
+/// assert_eq!(Some(key), rodeo.get("σίσυφος"));
+/// assert_eq!("Σίσυφος", rodeo.resolve(&key));
+/// ```
+///
+/// [`Normalizer`]: crate::Normalizer
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SimpleCaseFold;
+
+impl SimpleCaseFold {
+    /// Folds a single character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::SimpleCaseFold;
+    ///
+    /// assert_eq!('a', SimpleCaseFold::fold('A'));
+    /// assert_eq!(SimpleCaseFold::fold('σ'), SimpleCaseFold::fold('ς'));
+    /// assert_eq!('ß', SimpleCaseFold::fold('ß'));
+    /// ```
+    ///
+    #[inline]
+    pub fn fold(c: char) -> char {
+        if c.is_ascii() {
+            return c.to_ascii_lowercase();
+        }
+
+        // The dotless i has no folding, but its uppercase form is the ascii `I`
+        if c == '\u{131}' {
+            return c;
+        }
+
+        // Going through the uppercase form merges characters like the final sigma and
+        // the micro sign with the rest of their case class
+        let upper = single(c.to_uppercase()).unwrap_or(c);
+        single(upper.to_lowercase())
+            .or_else(|| single(c.to_lowercase()))
+            .unwrap_or(c)
+    }
+}
+
+/// Returns the only item of the iterator, if it has exactly one
+#[inline]
+fn single(mut iter: impl Iterator<Item = char>) -> Option<char> {
+    match (iter.next(), iter.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl Normalizer<str> for SimpleCaseFold {
+    #[inline]
+    fn hash<H: Hasher>(value: &str, state: &mut H) {
+        for c in value.chars() {
+            state.write_u32(Self::fold(c) as u32);
+        }
+
+        state.write_u8(0xFF);
+    }
+
+    #[inline]
+    fn eq(left: &str, right: &str) -> bool {
+        left.chars()
+            .map(Self::fold)
+            .eq(right.chars().map(Self::fold))
+    }
+}
+
+/// A value whose `Hash` and `Eq` impls are those of the normalizer `N`, used as the key
+/// type for the maps of interners
+#[repr(transparent)]
+pub(crate) struct Normalized<V: ?Sized, N> {
+    __normalizer: PhantomData<fn() -> N>,
+    value: V,
+}
+
+impl<V: ?Sized, N> Normalized<V, N> {
+    /// Views a value as normalized by `N`
+    #[inline]
+    pub(crate) fn new(value: &V) -> &Self {
+        // Safety: `Normalized` is a transparent wrapper around `V`
+        unsafe { &*(value as *const V as *const Self) }
+    }
+
+    /// Gets the value as it was given, before normalization
+    #[inline]
+    pub(crate) fn value(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<V, N> Hash for Normalized<V, N>
+where
+    V: Internable + ?Sized,
+    N: Normalizer<V>,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        N::hash(&self.value, state);
+    }
+}
+
+impl<V, N> PartialEq for Normalized<V, N>
+where
+    V: Internable + ?Sized,
+    N: Normalizer<V>,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        N::eq(&self.value, &other.value)
+    }
+}
+
+impl<V, N> Eq for Normalized<V, N>
+where
+    V: Internable + ?Sized,
+    N: Normalizer<V>,
+{
+}
+
+impl<V: fmt::Debug + ?Sized, N> fmt::Debug for Normalized<V, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsciiCaseInsensitive, AsciiLowercase, Normalizer, Rodeo, SimpleCaseFold, Spur};
+    use core::hash::Hasher;
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::string::String;
+        }
+    }
+
+    /// Treats all runs of whitespace as a single space, storing the collapsed form
+    struct CollapseWhitespace;
+
+    impl CollapseWhitespace {
+        fn words(value: &str) -> impl Iterator<Item = &str> {
+            value.split_whitespace()
+        }
+    }
+
+    impl Normalizer<str> for CollapseWhitespace {
+        fn hash<H: Hasher>(value: &str, state: &mut H) {
+            for word in Self::words(value) {
+                state.write(word.as_bytes());
+                state.write_u8(0xFF);
+            }
+        }
+
+        fn eq(left: &str, right: &str) -> bool {
+            Self::words(left).eq(Self::words(right))
+        }
+
+        fn canonicalize<F, R>(value: &str, store: F) -> R
+        where
+            F: FnOnce(&str) -> R,
+        {
+            let mut collapsed = String::new();
+            for word in Self::words(value) {
+                if !collapsed.is_empty() {
+                    collapsed.push(' ');
+                }
+                collapsed.push_str(word);
+            }
+
+            store(&collapsed)
+        }
+    }
+
+    #[test]
+    fn ascii_case_insensitive() {
+        let mut rodeo: Rodeo<str, Spur, _, AsciiCaseInsensitive> = Rodeo::with_normalizer();
+
+        let select = rodeo.get_or_intern("SELECT");
+        assert_eq!(select, rodeo.get_or_intern("select"));
+        assert_eq!(Some(select), rodeo.try_get_or_intern("SeLeCt"));
+        assert_eq!(Some(select), rodeo.get("Select"));
+        assert_eq!("SELECT", rodeo.resolve(&select));
+
+        let from = rodeo.get_or_intern("from");
+        assert_ne!(select, from);
+        assert_eq!(None, rodeo.get("selected"));
+        assert_eq!(2, rodeo.len());
+
+        // Longer than a single hashing chunk
+        let long = "A Much Longer Identifier That Spans Multiple Hashing Chunks";
+        let key = rodeo.get_or_intern(long);
+        assert_eq!(Some(key), rodeo.get(long.to_ascii_uppercase()));
+        assert_eq!(Some(key), rodeo.get(long.to_ascii_lowercase()));
+        assert_eq!(None, rodeo.get(&long[1..]));
+
+        // Non-ascii characters are compared exactly
+        let upper = rodeo.get_or_intern("Ä");
+        assert_ne!(upper, rodeo.get_or_intern("ä"));
+
+        let reader = rodeo.into_reader();
+        assert_eq!(Some(select), reader.get("sElEcT"));
+        assert_eq!(Some(from), reader.get("FROM"));
+        assert_eq!("from", reader.resolve(&from));
+    }
+
+    #[test]
+    fn ascii_lowercase() {
+        let mut rodeo: Rodeo<str, Spur, _, AsciiLowercase> = Rodeo::with_normalizer();
+
+        let key = rodeo.get_or_intern("Table_Name");
+        assert_eq!(key, rodeo.get_or_intern("TABLE_NAME"));
+        assert_eq!("table_name", rodeo.resolve(&key));
+
+        let mut bytes: Rodeo<[u8], Spur, _, AsciiLowercase> = Rodeo::with_normalizer();
+        let key = bytes.get_or_intern(b"BYTES");
+        assert_eq!(Some(key), bytes.get(b"bytes"));
+        assert_eq!(b"bytes", bytes.resolve(&key));
+    }
+
+    #[test]
+    fn simple_case_fold() {
+        let pairs = [
+            ('A', 'a'),
+            ('Ä', 'ä'),
+            ('Σ', 'ς'),
+            ('σ', 'ς'),
+            ('\u{B5}', 'μ'),
+            ('\u{17F}', 's'),
+            ('\u{212A}', 'k'),
+            ('\u{1E9E}', 'ß'),
+            ('ǅ', 'ǆ'),
+            ('Ꭰ', 'ꭰ'),
+        ];
+        for &(left, right) in pairs.iter() {
+            assert_eq!(SimpleCaseFold::fold(left), SimpleCaseFold::fold(right));
+        }
+
+        let distinct = [('ı', 'i'), ('İ', 'i'), ('ß', 's'), ('a', 'b')];
+        for &(left, right) in distinct.iter() {
+            assert_ne!(SimpleCaseFold::fold(left), SimpleCaseFold::fold(right));
+        }
+
+        let mut rodeo: Rodeo<str, Spur, _, SimpleCaseFold> = Rodeo::with_normalizer();
+        let key = rodeo.get_or_intern("Straße");
+        assert_eq!(Some(key), rodeo.get("STRAẞE"));
+        assert_eq!(None, rodeo.get("STRASSE"));
+        assert_eq!("Straße", rodeo.resolve(&key));
+    }
+
+    #[test]
+    fn custom() {
+        let mut rodeo: Rodeo<str, Spur, _, CollapseWhitespace> = Rodeo::with_normalizer();
+
+        let key = rodeo.get_or_intern("  hello \t world\n");
+        assert_eq!(key, rodeo.get_or_intern("hello world"));
+        assert_eq!(Some(key), rodeo.get("hello\nworld"));
+        assert_eq!(None, rodeo.get("helloworld"));
+        assert_eq!("hello world", rodeo.resolve(&key));
+    }
+
+    #[test]
+    #[cfg(all(not(any(miri, feature = "no-std")), feature = "multi-threaded"))]
+    fn threaded() {
+        use crate::ThreadedRodeo;
+        use std::{sync::Arc, thread};
+
+        let rodeo: Arc<ThreadedRodeo<str, Spur, _, AsciiLowercase>> =
+            Arc::new(ThreadedRodeo::with_normalizer());
+        let key = rodeo.get_or_intern("Hello");
+
+        let moved = Arc::clone(&rodeo);
+        thread::spawn(move || {
+            assert_eq!(key, moved.get_or_intern("HELLO"));
+            assert_eq!(Some(key), moved.get("hElLo"));
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!("hello", rodeo.resolve(&key));
+        assert_eq!(1, rodeo.len());
+
+        let reader = Arc::try_unwrap(rodeo).ok().unwrap().into_reader();
+        assert_eq!(Some(key), reader.get("HeLLo"));
+        assert_eq!("hello", reader.resolve(&key));
+    }
+}
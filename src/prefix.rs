@@ -0,0 +1,241 @@
+use crate::{key::Key, table::StringTable};
+
+use core::{iter::FusedIterator, slice};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::vec::Vec;
+    }
+}
+
+/// An index over interned strings that holds every key sorted by its string, so all strings
+/// sharing a prefix sit in a single contiguous run that can be found with two binary searches
+#[derive(Debug, Clone)]
+pub(crate) struct PrefixIndex<K: Key> {
+    sorted: Vec<K>,
+}
+
+impl<K: Key> PrefixIndex<K> {
+    /// Creates the index for the given strings, where each string's index is its key
+    #[inline]
+    pub(crate) fn new(strings: &StringTable<str>) -> Self {
+        assert!(
+            !K::can_inline::<str>(),
+            "Indices can't be built over keys that hold strings within themselves"
+        );
+
+        let mut sorted = (0..strings.len())
+            .map(|idx| K::try_from_usize(idx).unwrap_or_else(|| unreachable!()))
+            .collect::<Vec<K>>();
+
+        // Safety: Every key was just created from an index into `strings`
+        sorted.sort_unstable_by(|a, b| unsafe { resolve(strings, a).cmp(resolve(strings, b)) });
+
+        Self { sorted }
+    }
+
+    /// Gets the keys of all strings starting with `prefix`, sorted by their strings
+    ///
+    /// # Safety
+    ///
+    /// `strings` must be the same strings the index was created with
+    ///
+    #[inline]
+    pub(crate) unsafe fn with_prefix<'a>(
+        &'a self,
+        strings: &StringTable<str>,
+        prefix: &str,
+    ) -> &'a [K] {
+        // Everything before `start` is smaller than the prefix, and since the strings that start with the prefix
+        // are the smallest strings that aren't smaller than it, they're a run that begins at `start`
+        let start = self
+            .sorted
+            .partition_point(|key| resolve(strings, key) < prefix);
+        let len =
+            self.sorted[start..].partition_point(|key| resolve(strings, key).starts_with(prefix));
+
+        &self.sorted[start..start + len]
+    }
+}
+
+/// Resolves a key from the index
+///
+/// # Safety
+///
+/// The key must be an index into `strings`
+///
+#[inline]
+unsafe fn resolve<'a, K: Key>(strings: &'a StringTable<str>, key: &K) -> &'a str {
+    strings.get_unchecked(key.into_usize())
+}
+
+/// A sorted iterator over the keys of strings starting with a prefix, created with `keys_with_prefix`
+#[derive(Debug, Clone)]
+pub struct KeysWithPrefix<'a, K: Key> {
+    iter: slice::Iter<'a, K>,
+}
+
+impl<'a, K: Key> KeysWithPrefix<'a, K> {
+    #[inline]
+    pub(crate) fn new(keys: &'a [K]) -> Self {
+        Self { iter: keys.iter() }
+    }
+}
+
+impl<'a, K: Key> Iterator for KeysWithPrefix<'a, K> {
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().copied()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Key> DoubleEndedIterator for KeysWithPrefix<'a, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().copied()
+    }
+}
+
+impl<'a, K: Key> ExactSizeIterator for KeysWithPrefix<'a, K> {}
+
+impl<'a, K: Key> FusedIterator for KeysWithPrefix<'a, K> {}
+
+/// A sorted iterator over the strings starting with a prefix, created with `strings_with_prefix`
+#[derive(Debug, Clone)]
+pub struct StringsWithPrefix<'a, K: Key> {
+    iter: slice::Iter<'a, K>,
+    strings: &'a StringTable<str>,
+}
+
+impl<'a, K: Key> StringsWithPrefix<'a, K> {
+    /// Creates an iterator over the strings of the given keys
+    ///
+    /// # Safety
+    ///
+    /// Every key must be an index into `strings`
+    ///
+    #[inline]
+    pub(crate) unsafe fn new(keys: &'a [K], strings: &'a StringTable<str>) -> Self {
+        Self {
+            iter: keys.iter(),
+            strings,
+        }
+    }
+}
+
+impl<'a, K: Key> Iterator for StringsWithPrefix<'a, K> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let strings = self.strings;
+
+        // Safety: The keys were checked when the iterator was created
+        self.iter.next().map(|key| unsafe { resolve(strings, key) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Key> DoubleEndedIterator for StringsWithPrefix<'a, K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let strings = self.strings;
+
+        // Safety: The keys were checked when the iterator was created
+        self.iter
+            .next_back()
+            .map(|key| unsafe { resolve(strings, key) })
+    }
+}
+
+impl<'a, K: Key> ExactSizeIterator for StringsWithPrefix<'a, K> {}
+
+impl<'a, K: Key> FusedIterator for StringsWithPrefix<'a, K> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Spur;
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::vec;
+        }
+    }
+
+    fn with_prefix<'a>(strings: &[&'a str], prefix: &str) -> Vec<&'a str> {
+        let table = StringTable::new(strings, None);
+        let index = PrefixIndex::<Spur>::new(&table);
+        let keys = unsafe { index.with_prefix(&table, prefix) };
+
+        unsafe { keys.iter().map(|key| strings[key.into_usize()]).collect() }
+    }
+
+    #[test]
+    fn sorted_runs() {
+        let strings = ["parse", "b", "parent", "par", "pa", "partial", "q", ""];
+
+        assert_eq!(
+            vec!["par", "parent", "parse", "partial"],
+            with_prefix(&strings, "par")
+        );
+        assert_eq!(vec!["parent"], with_prefix(&strings, "pare"));
+        assert_eq!(
+            vec!["pa", "par", "parent", "parse", "partial"],
+            with_prefix(&strings, "pa")
+        );
+        assert_eq!(vec!["q"], with_prefix(&strings, "q"));
+        assert!(with_prefix(&strings, "parz").is_empty());
+        assert!(with_prefix(&strings, "z").is_empty());
+        assert_eq!(strings.len(), with_prefix(&strings, "").len());
+    }
+
+    #[test]
+    fn multibyte() {
+        let strings = ["über", "überall", "uber", "ü", "u"];
+
+        assert_eq!(vec!["ü", "über", "überall"], with_prefix(&strings, "ü"));
+        assert_eq!(vec!["u", "uber"], with_prefix(&strings, "u"));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(with_prefix(&[], "").is_empty());
+        assert!(with_prefix(&[], "a").is_empty());
+    }
+
+    #[test]
+    fn keys() {
+        let strings = StringTable::new(&["c", "ab", "a", "b"], None);
+        let index = PrefixIndex::<Spur>::new(&strings);
+
+        let keys = KeysWithPrefix::new(unsafe { index.with_prefix(&strings, "a") });
+        assert_eq!(2, keys.len());
+        assert_eq!(
+            vec![
+                Spur::try_from_usize(2).unwrap(),
+                Spur::try_from_usize(1).unwrap()
+            ],
+            keys.collect::<Vec<_>>(),
+        );
+
+        let keys = KeysWithPrefix::new(unsafe { index.with_prefix(&strings, "") });
+        assert_eq!(
+            vec![0, 3, 1, 2],
+            keys.rev()
+                .map(|key| unsafe { key.into_usize() })
+                .collect::<Vec<_>>(),
+        );
+    }
+}
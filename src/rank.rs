@@ -0,0 +1,235 @@
+use crate::{internable::Internable, key::Key, table::StringTable, util::sorted_order};
+
+use core::{cmp::Ordering, convert::TryFrom, marker::PhantomData};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::{vec, vec::Vec};
+    }
+}
+
+/// The lexicographic rank of every key of an interner, letting keys be compared and sorted by their strings
+/// with integer comparisons instead of resolving them, created with `rank_table`
+///
+/// Keys of equal strings have equal ranks, and the rank of a string is the number of smaller strings
+/// that come before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankTable<K: Key> {
+    /// The rank of every key, indexed by the key
+    ranks: Vec<u32>,
+    __key: PhantomData<K>,
+}
+
+impl<K: Key> RankTable<K> {
+    /// Ranks the given strings, where each string's index is its key
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `u32::MAX` strings
+    ///
+    #[inline]
+    pub(crate) fn new<V>(strings: &StringTable<V>) -> Self
+    where
+        V: Internable + Ord + ?Sized,
+    {
+        assert!(
+            !K::can_inline::<V>(),
+            "Indices can't be built over keys that hold strings within themselves"
+        );
+
+        let mut ranks = vec![0; strings.len()];
+
+        // Safety: Every index given to the closure is less than the number of strings
+        let string = |idx| unsafe { strings.get_unchecked(idx) };
+
+        let mut previous: Option<(&V, u32)> = None;
+        for (position, idx) in sorted_order(strings.len(), string).into_iter().enumerate() {
+            let string = string(idx);
+
+            let rank = match previous {
+                Some((last, rank)) if last == string => rank,
+                _ => u32::try_from(position).expect("More than u32::MAX strings can't be ranked"),
+            };
+
+            ranks[idx] = rank;
+            previous = Some((string, rank));
+        }
+
+        Self {
+            ranks,
+            __key: PhantomData,
+        }
+    }
+
+    /// Gets the rank of a key
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let b = rodeo.get_or_intern("b");
+    /// let a = rodeo.get_or_intern("a");
+    ///
+    /// let ranks = rodeo.into_reader().rank_table();
+    /// assert_eq!(0, ranks.rank(&a));
+    /// assert_eq!(1, ranks.rank(&b));
+    /// ```
+    ///
+    #[inline]
+    pub fn rank(&self, key: &K) -> u32 {
+        self.try_rank(key)
+            .expect("The key doesn't belong to the ranked interner")
+    }
+
+    /// Gets the rank of a key, returning `None` if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Key, Rodeo, Spur};
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("a");
+    ///
+    /// let ranks = rodeo.into_reader().rank_table();
+    /// assert_eq!(Some(0), ranks.try_rank(&key));
+    /// assert_eq!(None, ranks.try_rank(&Spur::try_from_usize(10).unwrap()));
+    /// ```
+    ///
+    #[inline]
+    pub fn try_rank(&self, key: &K) -> Option<u32> {
+        // Safety: The index is bounds checked, so a fabricated key can only fail to be found
+        self.ranks.get(unsafe { key.into_usize() }).copied()
+    }
+
+    /// Compares two keys by their strings
+    ///
+    /// # Panics
+    ///
+    /// Panics if either key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    /// use std::cmp::Ordering;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let b = rodeo.get_or_intern("b");
+    /// let a = rodeo.get_or_intern("a");
+    ///
+    /// let ranks = rodeo.into_reader().rank_table();
+    /// assert_eq!(Ordering::Less, ranks.cmp_by_str(&a, &b));
+    /// assert_eq!(Ordering::Equal, ranks.cmp_by_str(&b, &b));
+    /// ```
+    ///
+    #[inline]
+    pub fn cmp_by_str(&self, a: &K, b: &K) -> Ordering {
+        self.rank(a).cmp(&self.rank(b))
+    }
+
+    /// Sorts keys by their strings, keeping the keys of equal strings in the order they were given
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let mut keys = vec![
+    ///     rodeo.get_or_intern("c"),
+    ///     rodeo.get_or_intern("a"),
+    ///     rodeo.get_or_intern("b"),
+    /// ];
+    ///
+    /// let reader = rodeo.into_reader();
+    /// reader.rank_table().sort_keys_by_str(&mut keys);
+    /// assert_eq!(
+    ///     vec!["a", "b", "c"],
+    ///     keys.iter().map(|key| reader.resolve(key)).collect::<Vec<_>>(),
+    /// );
+    /// ```
+    ///
+    #[inline]
+    pub fn sort_keys_by_str(&self, keys: &mut [K]) {
+        keys.sort_by_key(|key| self.rank(key));
+    }
+
+    /// Gets the number of ranked keys
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ranks.len()
+    }
+
+    /// Returns `true` if there are no ranked keys
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranks.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Spur;
+
+    fn ranks(strings: &[&str]) -> Vec<u32> {
+        let table = RankTable::<Spur>::new(&StringTable::new(strings, None));
+
+        (0..strings.len())
+            .map(|idx| table.rank(&Spur::try_from_usize(idx).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn lexicographic() {
+        assert_eq!(vec![2, 0, 3, 1], ranks(&["c", "", "ü", "b"]));
+        assert_eq!(vec![1, 0, 2], ranks(&["ab", "a", "b"]));
+        assert!(ranks(&[]).is_empty());
+    }
+
+    #[test]
+    fn equal_strings() {
+        assert_eq!(vec![1, 0, 1, 3], ranks(&["b", "a", "b", "c"]));
+    }
+
+    #[test]
+    fn sort_keys() {
+        let table = RankTable::<Spur>::new(&StringTable::<str>::new(&["b", "a", "b", "c"], None));
+
+        let mut keys = [3, 2, 1, 0]
+            .iter()
+            .map(|&idx| Spur::try_from_usize(idx).unwrap())
+            .collect::<Vec<_>>();
+        table.sort_keys_by_str(&mut keys);
+
+        assert_eq!(
+            vec![1, 2, 0, 3],
+            keys.iter()
+                .map(|key| unsafe { key.into_usize() })
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn out_of_bounds() {
+        RankTable::<Spur>::new(&StringTable::<str>::new(&["a"], None))
+            .rank(&Spur::try_from_usize(1).unwrap());
+    }
+}
@@ -1,293 +1,1213 @@
 use crate::{
-    arena::Arena,
-    hasher::{HashMap, RandomState},
-    internable::Internable,
+    bloom::BloomFilter,
+    hasher::RandomState,
+    internable::{AsInternable, Internable},
     key::{Key, Spur},
+    nearest::{BkTree, Nearest},
+    normalizer::{Identity, Normalized, Normalizer, SimpleCaseFold},
+    prefix::{KeysWithPrefix, PrefixIndex, StringsWithPrefix},
+    rank::RankTable,
+    remap::KeyRemap,
     resolver::RodeoResolver,
-    util::{Iter, Strings},
+    sorted::SortedReader,
+    table::StringTable,
+    utf16::{EncodeUtf16, Utf16Str},
+    util::{find_key, inline_key, Iter, KeyMap, Strings},
 };
 
-use core::{hash::BuildHasher, mem};
+use core::{hash::BuildHasher, marker::PhantomData, mem};
+use hashbrown::hash_map::RawEntryMut;
 
 compile! {
     if #[feature = "no-std"] {
-        use alloc::vec::Vec;
+        use alloc::{string::String, vec::Vec};
     }
 }
 
 /// A read-only view of a [`Rodeo`] or [`ThreadedRodeo`] that allows contention-free access to interned strings,
 /// both key to string resolution and string to key lookups
 ///
-/// The key and hasher types are the same as the `Rodeo` or `ThreadedRodeo` that created it, can be acquired with the
-/// `into_reader` methods.
+/// The key, hasher and [`Normalizer`] types are the same as the `Rodeo` or `ThreadedRodeo` that created it,
+/// can be acquired with the `into_reader` methods.
+///
+/// [`Normalizer`]: crate::Normalizer
 ///
 /// [`Rodeo`]: crate::Rodeo
 /// [`ThreadedRodeo`]: crate::ThreadedRodeo
 #[derive(Debug)]
-pub struct RodeoReader<V = str, K = Spur, S = RandomState>
+pub struct RodeoReader<V = str, K = Spur, S = RandomState, N = Identity>
 where
     V: Internable + ?Sized,
     K: Key,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
-    map: HashMap<&'static V, K, S>,
-    pub(crate) strings: Vec<&'static V>,
-    arena: Arena<V::Raw>,
+    /// Map that allows `str` -> `key` resolution, holding only keys that are compared through `table`
+    map: KeyMap<K, S>,
+    /// The strings indexed by key
+    pub(crate) table: StringTable<V>,
+    /// The hash of every string, allowing them to be moved between maps without rehashing
+    hashes: Vec<u64>,
+    /// The optional case-insensitive index, mapping the first key of each case folded string to all of
+    /// its keys, where keys are compared through `table` just like in `map`
+    case_insensitive: Option<hashbrown::HashMap<K, Vec<K>, S>>,
+    /// The optional prefix index, holding every key sorted by its string
+    prefix: Option<PrefixIndex<K>>,
+    /// The optional BK-tree used to find the strings nearest to another string
+    nearest: Option<BkTree<K>>,
+    /// The optional bloom filter over the strings' hashes, rejecting most lookups of strings that aren't interned
+    bloom: Option<BloomFilter>,
+    __normalizer: PhantomData<N>,
 }
 
-impl<V, K, S> RodeoReader<V, K, S>
+impl<V, K, S, N> RodeoReader<V, K, S, N>
 where
     V: Internable + ?Sized,
     K: Key,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
-    /// Creates a new RodeoReader
+    /// Creates a new RodeoReader from a map holding the key of every string of the table
+    #[inline]
+    pub(crate) fn new(map: KeyMap<K, S>, table: StringTable<V>, hashes: Vec<u64>) -> Self {
+        debug_assert!(map.len() == table.len() && table.len() == hashes.len());
+
+        Self {
+            map,
+            table,
+            hashes,
+            case_insensitive: None,
+            prefix: None,
+            nearest: None,
+            bloom: None,
+            __normalizer: PhantomData,
+        }
+    }
+
+    /// Get the key value of a string, returning `None` if it doesn't exist
+    ///
+    /// With keys that hold short strings within themselves like [`InlineSpur`], a key is returned for
+    /// **every** short string, including ones that were never interned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// assert_eq!(Some(key), rodeo.get("Strings of things with wings and dings"));
+    ///
+    /// assert_eq!(None, rodeo.get("This string isn't interned"));
+    /// ```
+    ///
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        let val = Normalized::<V, N>::new(val.as_internable());
+        if let Some(key) = inline_key(val, self.table.is_terminated()) {
+            return Some(key);
+        }
+
+        // The string is hashed once for both the filter and the map
+        self.find(val, self.map.hasher().hash_one(val))
+    }
+
+    /// Get the key value of a string using a hash that was already computed with [`hash_one`],
+    /// returning `None` if it doesn't exist
+    ///
+    /// The string is never rehashed. Giving a hash that doesn't match the string won't cause unsafety,
+    /// but may fail to find the string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    /// assert_eq!(Some(key), rodeo.get_with_hash("Strings of things with wings and dings", hash));
+    /// ```
+    ///
+    /// [`hash_one`]: RodeoReader::hash_one
+    #[inline]
+    pub fn get_with_hash<T>(&self, val: T, hash: u64) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        let val = Normalized::<V, N>::new(val.as_internable());
+        if let Some(key) = inline_key(val, self.table.is_terminated()) {
+            return Some(key);
+        }
+
+        self.find(val, hash)
+    }
+
+    /// Finds the key of a stored string by its hash, checking the bloom filter before the map. Strings
+    /// held within keys are never stored, so they have to be handled beforehand
+    #[inline]
+    fn find(&self, val: &Normalized<V, N>, hash: u64) -> Option<K> {
+        if matches!(&self.bloom, Some(bloom) if !bloom.may_contain(hash)) {
+            return None;
+        }
+
+        find_key(&self.map, hash, |idx| {
+            // Safety: Every key in the map was made for one of the strings
+            Normalized::new(unsafe { self.table.get_unchecked(idx) }) == val
+        })
+    }
+
+    /// Builds a bloom filter over the hashes of every string, which lets [`get`] and [`get_with_hash`] turn away
+    /// most strings that aren't interned before probing the map. Building the filter again with a different
+    /// false positive rate replaces it
+    ///
+    /// At most `false_positive_rate` of the strings that aren't interned make it past the filter, and lower rates
+    /// take more memory: a rate of 1% takes about 11 bits per string and a rate of 0.1% takes about 16. The filter
+    /// is built from the strings' cached hashes, so nothing is rehashed
+    ///
+    /// Checking the filter touches a single cache line, so it pays off when most lookups miss and the map is too
+    /// large to stay in cache. Strings that make it past the filter probe the map with the same hash
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` isn't between zero and one exclusive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let mut rodeo = rodeo.into_reader();
+    /// rodeo.build_bloom_filter(0.01);
+    ///
+    /// assert!(rodeo.has_bloom_filter());
+    /// assert_eq!(Some(key), rodeo.get("Strings of things with wings and dings"));
+    /// assert_eq!(None, rodeo.get("This string isn't interned"));
+    /// ```
+    ///
+    /// [`get`]: RodeoReader::get
+    /// [`get_with_hash`]: RodeoReader::get_with_hash
+    #[inline]
+    pub fn build_bloom_filter(&mut self, false_positive_rate: f64) {
+        if !matches!(&self.bloom, Some(bloom) if bloom.false_positive_rate() == false_positive_rate)
+        {
+            self.bloom = Some(BloomFilter::new(&self.hashes, false_positive_rate));
+        }
+    }
+
+    /// Returns `true` if the bloom filter has been built
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default().into_reader();
+    /// assert!(!rodeo.has_bloom_filter());
+    ///
+    /// rodeo.build_bloom_filter(0.01);
+    /// assert!(rodeo.has_bloom_filter());
+    /// ```
+    ///
+    #[inline]
+    pub fn has_bloom_filter(&self) -> bool {
+        self.bloom.is_some()
+    }
+
+    /// Returns the number of bytes used by the bloom filter, or `None` if it hasn't been built
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// for i in 0..1000 {
+    ///     rodeo.get_or_intern(i.to_string());
+    /// }
+    ///
+    /// let mut rodeo = rodeo.into_reader();
+    /// assert_eq!(None, rodeo.bloom_filter_size());
+    ///
+    /// rodeo.build_bloom_filter(0.1);
+    /// let loose = rodeo.bloom_filter_size().unwrap();
+    ///
+    /// rodeo.build_bloom_filter(0.001);
+    /// assert!(loose < rodeo.bloom_filter_size().unwrap());
+    /// ```
+    ///
+    #[inline]
+    pub fn bloom_filter_size(&self) -> Option<usize> {
+        self.bloom.as_ref().map(BloomFilter::size_in_bytes)
+    }
+
+    /// Hashes a string with the RodeoReader's hasher and [`Normalizer`], giving the hash
+    /// that [`get_with_hash`] expects
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// assert_eq!(rodeo.hash_of(&key), rodeo.hash_one("Strings of things with wings and dings"));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    /// [`get_with_hash`]: RodeoReader::get_with_hash
+    #[inline]
+    pub fn hash_one<T>(&self, val: T) -> u64
+    where
+        T: AsInternable<V>,
+    {
+        self.map
+            .hasher()
+            .hash_one(Normalized::<V, N>::new(val.as_internable()))
+    }
+
+    /// Resolves a string by its key. Only keys made by the current Resolver or the creator
+    /// of the current Resolver may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let rodeo = rodeo.into_resolver();
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`Key`]: crate::Key
+    #[inline]
+    pub fn resolve<'a>(&'a self, key: &K) -> &'a V {
+        // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
+        // being symmetric and the caller having not fabricated a key. If the impl is sound
+        // and symmetric, then it will succeed, as the usize used to create it is a valid
+        // index into self.table
+        unsafe {
+            assert!(key.into_usize() < self.table.len());
+            self.table.get_unchecked(key.into_usize())
+        }
+    }
+
+    /// Resolves a string by its key, returning `None` if the key is out of bounds. Only keys
+    /// made by the current Resolver or the creator of the current Resolver may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let rodeo = rodeo.into_resolver();
+    /// assert_eq!(Some("Strings of things with wings and dings"), rodeo.try_resolve(&key));
+    /// ```
+    ///
+    /// [`Key`]: crate::Key
+    #[inline]
+    pub fn try_resolve<'a>(&'a self, key: &K) -> Option<&'a V> {
+        // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
+        // being symmetric and the caller having not fabricated a key. If the impl is sound
+        // and symmetric, then it will succeed, as the usize used to create it is a valid
+        // index into self.table
+        unsafe { self.table.get(key.into_usize()) }
+    }
+
+    /// Resolves a string by its key without bounds checks
+    ///
+    /// # Safety
+    ///
+    /// The key must be valid for the current Reader and can't hold its string within itself, see [`Key::is_inline`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let rodeo = rodeo.into_resolver();
+    /// unsafe {
+    ///     assert_eq!("Strings of things with wings and dings", rodeo.resolve_unchecked(&key));
+    /// }
+    /// ```
+    ///
+    /// [`Key`]: crate::Key
+    #[inline]
+    pub unsafe fn resolve_unchecked<'a>(&'a self, key: &K) -> &'a V {
+        self.table.get_unchecked(key.into_usize())
+    }
+
+    /// Gets the number of interned strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("Documentation often has little hidden bits in it");
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// assert_eq!(rodeo.len(), 1);
+    /// ```
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns `true` if there are no currently interned strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let rodeo = Rodeo::default();
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// assert!(rodeo.is_empty());
+    /// ```
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the hash of a string by its key, which is the same hash the RodeoReader's map uses.
+    /// Only keys made by the current Reader or the creator of the current Reader may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    /// let hash = rodeo.hash_of(&key);
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// assert_eq!(hash, rodeo.hash_of(&key));
+    /// ```
+    ///
+    #[inline]
+    pub fn hash_of(&self, key: &K) -> u64 {
+        if let Some(string) = key.as_inline() {
+            return self.map.hasher().hash_one(Normalized::<V, N>::new(string));
+        }
+
+        // Safety: The index is bounds checked, so a malformed key can only cause a panic
+        unsafe { self.hashes[key.into_usize()] }
+    }
+
+    /// Returns an iterator over the interned strings and their key values
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, V, K> {
+        Iter::from_reader(self)
+    }
+
+    /// Returns an iterator over the interned strings
+    #[inline]
+    pub fn strings(&self) -> Strings<'_, V, K> {
+        Strings::from_reader(self)
+    }
+
+    /// Consumes the current rodeo, making it into a [`RodeoResolver`], allowing
+    /// contention-free access from multiple threads with the lowest possible memory consumption
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    /// let reader_rodeo = rodeo.into_reader();
+    ///
+    /// let resolver_rodeo = reader_rodeo.into_resolver();
+    /// assert_eq!(
+    ///     "Appear weak when you are strong, and strong when you are weak.",
+    ///     resolver_rodeo.resolve(&key),
+    /// );
+    /// ```
+    ///
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    #[inline]
+    #[must_use]
+    pub fn into_resolver(mut self) -> RodeoResolver<V, K> {
+        // Drop the map's allocation without visiting any of its entries
+        self.map = KeyMap::with_hasher(self.map.hasher().clone());
+        self.case_insensitive = None;
+
+        let mut resolver = RodeoResolver::new(mem::take(&mut self.table));
+        resolver.prefix = self.prefix.take();
+
+        resolver
+    }
+}
+
+impl<V, K, S, N> RodeoReader<V, K, S, N>
+where
+    V: Internable + Ord + ?Sized,
+    K: Key,
+    S: BuildHasher + Clone,
+    N: Normalizer<V>,
+{
+    /// Ranks every key by its string, creating a [`RankTable`] that compares and sorts keys in
+    /// lexicographic order without resolving them
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `u32::MAX` strings, or if the keys can hold strings within themselves
+    /// like [`InlineSpur`], since those strings are never stored and can't be ranked
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let mut keys = vec![rodeo.get_or_intern("b"), rodeo.get_or_intern("a")];
+    ///
+    /// let rodeo = rodeo.into_reader();
+    /// let ranks = rodeo.rank_table();
+    /// assert!(ranks.rank(&keys[1]) < ranks.rank(&keys[0]));
+    ///
+    /// ranks.sort_keys_by_str(&mut keys);
+    /// assert_eq!("a", rodeo.resolve(&keys[0]));
+    /// ```
+    ///
+    /// [`RankTable`]: crate::RankTable
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn rank_table(&self) -> RankTable<K> {
+        RankTable::new(&self.table)
+    }
+}
+
+impl<V, K, S, N> RodeoReader<V, K, S, N>
+where
+    V: Internable + ?Sized,
+    V::Raw: Ord,
+    K: Key,
+    S: BuildHasher + Clone,
+    N: Normalizer<V>,
+{
+    /// Stores every string that's a suffix of another interned string as the tail of that string instead
+    /// of on its own, returning the number of bytes saved compared to storing every string on its own.
+    /// Keys are unaffected and strings are still resolved in `O(1)`
+    ///
+    /// All strings are copied into a single new buffer, so sharing suffixes takes about as long as cloning
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let path = rodeo.get_or_intern("src/module/file.rs");
+    /// let file = rodeo.get_or_intern("file.rs");
+    ///
+    /// let mut rodeo = rodeo.into_reader();
+    /// assert_eq!("file.rs".len(), rodeo.share_suffixes());
+    ///
+    /// assert_eq!("src/module/file.rs", rodeo.resolve(&path));
+    /// assert_eq!("file.rs", rodeo.resolve(&file));
+    /// assert_eq!(Some(file), rodeo.get("file.rs"));
+    /// ```
+    ///
+    #[inline]
+    pub fn share_suffixes(&mut self) -> usize {
+        let (table, saved) = self.table.share_suffixes();
+
+        // The maps and indices only hold keys, so they stay as they are
+        self.table = table;
+
+        saved
+    }
+}
+
+impl<V, K, S> RodeoReader<V, K, S, Identity>
+where
+    V: Internable + Ord + ?Sized,
+    K: Key,
+    S: BuildHasher + Clone,
+{
+    /// Consumes the current reader, returning a [`SortedReader`] that finds strings with a binary search
+    /// instead of a hash map along with the [`KeyRemap`] from the current keys to the sorted reader's keys
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    /// let reader = rodeo.into_reader();
+    ///
+    /// let (sorted, remap) = reader.into_sorted_reader();
+    /// assert_eq!(
+    ///     "Appear weak when you are strong, and strong when you are weak.",
+    ///     sorted.resolve(&remap.remap(&key)),
+    /// );
+    /// ```
+    ///
+    /// [`SortedReader`]: crate::SortedReader
+    /// [`KeyRemap`]: crate::KeyRemap
+    #[inline]
+    #[must_use]
+    pub fn into_sorted_reader(mut self) -> (SortedReader<V, K>, KeyRemap<K>) {
+        // Drop the map's allocation without visiting any of its entries
+        self.map = KeyMap::with_hasher(self.map.hasher().clone());
+        self.case_insensitive = None;
+        self.prefix = None;
+        self.nearest = None;
+
+        SortedReader::sort(
+            &self.table.iter().collect::<Vec<_>>(),
+            self.table.terminator(),
+        )
+    }
+}
+
+impl<K, S> RodeoReader<Utf16Str, K, S>
+where
+    K: Key,
+    S: BuildHasher + Clone,
+{
+    /// Get the key of a UTF-16 string from its UTF-8 equivalent, returning `None` if it doesn't exist
+    ///
+    /// The string is encoded into UTF-16 as it's hashed and compared, so nothing is allocated
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur, Utf16String};
+    ///
+    /// let mut rodeo: Rodeo<lasso::Utf16Str, Spur> = Rodeo::new();
+    /// let key = rodeo.get_or_intern(Utf16String::from("Strings of things with wings and dings"));
+    ///
+    /// let reader = rodeo.into_reader();
+    /// assert_eq!(Some(key), reader.get_utf8("Strings of things with wings and dings"));
+    /// assert_eq!(None, reader.get_utf8("This string isn't interned"));
+    /// ```
+    ///
+    #[inline]
+    pub fn get_utf8(&self, string: &str) -> Option<K> {
+        let hash = self.map.hasher().hash_one(EncodeUtf16(string));
+        if matches!(&self.bloom, Some(bloom) if !bloom.may_contain(hash)) {
+            return None;
+        }
+
+        // Safety: Every key in the map was made for one of the strings
+        find_key(&self.map, hash, |idx| {
+            unsafe { self.table.get_unchecked(idx) }.eq(string)
+        })
+    }
+}
+
+impl<K, S, N> RodeoReader<str, K, S, N>
+where
+    K: Key,
+    S: BuildHasher + Clone,
+    N: Normalizer<str>,
+{
+    /// Resolves a string by its key into the given buffer, replacing its contents. Unlike [`resolve`], this
+    /// also resolves keys that hold their string within themselves, see [`InlineSpur`]. Only keys made by the
+    /// current RodeoReader or the creator of the current RodeoReader may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let reader = rodeo.into_reader();
+    /// let mut buf = String::new();
+    /// assert_eq!("+", reader.resolve_into(&plus, &mut buf));
+    /// assert_eq!("Strings of things with wings and dings", reader.resolve_into(&long, &mut buf));
+    /// ```
+    ///
+    /// [`resolve`]: RodeoReader::resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> &'b str {
+        self.try_resolve_into(key, buf).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents, or returns `None` and
+    /// leaves the buffer alone if the key is out of bounds. Unlike [`try_resolve`], this also resolves keys
+    /// that hold their string within themselves, see [`InlineSpur`]. Only keys made by the current
+    /// RodeoReader or the creator of the current RodeoReader may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let reader = rodeo.into_reader();
+    /// let mut buf = String::new();
+    /// assert_eq!(Some("+"), reader.try_resolve_into(&plus, &mut buf));
+    /// assert_eq!(None, reader.try_resolve_into(&InlineSpur::try_from_usize(10).unwrap(), &mut buf));
+    /// ```
+    ///
+    /// [`try_resolve`]: RodeoReader::try_resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn try_resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> Option<&'b str> {
+        let string = match key.as_inline::<str>() {
+            Some(string) => string,
+            None => self.try_resolve(key)?,
+        };
+
+        buf.clear();
+        buf.push_str(string);
+
+        Some(buf.as_str())
+    }
+
+    /// Builds the case-insensitive index used by [`get_all_case_insensitive`], grouping every
+    /// interned string by its [simple case folding]. Building the index again does nothing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let foo = rodeo.get_or_intern("Foo");
+    ///
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_case_insensitive_index();
+    ///
+    /// assert!(reader.has_case_insensitive_index());
+    /// assert_eq!(&[foo], reader.get_all_case_insensitive("foo"));
+    /// ```
+    ///
+    /// [`get_all_case_insensitive`]: RodeoReader::get_all_case_insensitive
+    /// [simple case folding]: crate::SimpleCaseFold
+    #[inline]
+    pub fn build_case_insensitive_index(&mut self) {
+        if self.case_insensitive.is_some() {
+            return;
+        }
+
+        let mut index = hashbrown::HashMap::with_hasher(self.map.hasher().clone());
+        for idx in 0..self.table.len() {
+            let key = K::try_from_usize(idx).unwrap_or_else(|| unreachable!());
+
+            // Safety: The index is in bounds
+            let string = unsafe { self.table.get_unchecked(idx) };
+            let hash = self.case_folded_hash(string);
+
+            let keys = match index
+                .raw_entry_mut()
+                .from_hash(hash, |first| self.case_folded_eq(first, string))
+            {
+                RawEntryMut::Occupied(entry) => entry.into_mut(),
+                RawEntryMut::Vacant(entry) => {
+                    let (_, keys) = entry.insert_with_hasher(hash, key, Vec::new(), |first| {
+                        // Safety: Every key in the index was made for one of the strings
+                        self.case_folded_hash(unsafe {
+                            self.table.get_unchecked(first.into_usize())
+                        })
+                    });
+
+                    keys
+                }
+            };
+            keys.push(key);
+        }
+        index.values_mut().for_each(Vec::shrink_to_fit);
+
+        self.case_insensitive = Some(index);
+    }
+
+    /// Returns `true` if the case-insensitive index has been built
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut reader = Rodeo::default().into_reader();
+    /// assert!(!reader.has_case_insensitive_index());
+    ///
+    /// reader.build_case_insensitive_index();
+    /// assert!(reader.has_case_insensitive_index());
+    /// ```
+    ///
+    #[inline]
+    pub fn has_case_insensitive_index(&self) -> bool {
+        self.case_insensitive.is_some()
+    }
+
+    /// Gets the keys of every string that's equal to `val` when ignoring case, using Unicode
+    /// [simple case folding]. The keys are in the order they were interned, and no keys are
+    /// returned when nothing matches
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_case_insensitive_index`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let upper = rodeo.get_or_intern("FOO");
+    /// let title = rodeo.get_or_intern("Foo");
+    /// rodeo.get_or_intern("Bar");
+    ///
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_case_insensitive_index();
+    ///
+    /// // Did you mean `FOO` or `Foo`?
+    /// assert_eq!(None, reader.get("foo"));
+    /// assert_eq!(&[upper, title], reader.get_all_case_insensitive("foo"));
+    /// assert!(reader.get_all_case_insensitive("baz").is_empty());
+    /// ```
+    ///
+    /// [simple case folding]: crate::SimpleCaseFold
+    /// [`build_case_insensitive_index`]: RodeoReader::build_case_insensitive_index
+    #[inline]
+    pub fn get_all_case_insensitive(&self, val: &str) -> &[K] {
+        let index = self
+            .case_insensitive
+            .as_ref()
+            .expect("The case-insensitive index has not been built");
+
+        index
+            .raw_entry()
+            .from_hash(self.case_folded_hash(val), |first| {
+                self.case_folded_eq(first, val)
+            })
+            .map_or(&[], |(_, keys)| keys.as_slice())
+    }
+
+    /// Hashes a string by its [simple case folding](crate::SimpleCaseFold)
+    #[inline]
+    fn case_folded_hash(&self, val: &str) -> u64 {
+        self.map
+            .hasher()
+            .hash_one(Normalized::<str, SimpleCaseFold>::new(val))
+    }
+
+    /// Returns `true` if the string of `key` has the same [simple case folding](crate::SimpleCaseFold) as `val`
+    #[inline]
+    fn case_folded_eq(&self, key: &K, val: &str) -> bool {
+        // Safety: Every key in the index was made for one of the strings
+        let string = unsafe { self.table.get_unchecked(key.into_usize()) };
+
+        <SimpleCaseFold as Normalizer<str>>::eq(string, val)
+    }
+
+    /// Builds the prefix index used by [`keys_with_prefix`] and [`strings_with_prefix`], which sorts
+    /// the keys of all interned strings so that strings sharing a prefix can be found with a binary
+    /// search. Building the index again does nothing
+    ///
+    /// The index is kept when the reader is turned into a [`RodeoResolver`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys can hold strings within themselves like [`InlineSpur`], since those strings are never
+    /// stored and the index would miss them
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
     ///
-    /// # Safety
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("parse");
     ///
-    /// The references inside of `strings` must be absolutely unique, meaning
-    /// that no other references to those strings exist
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_prefix_index();
     ///
-    pub(crate) unsafe fn new(
-        map: HashMap<&'static V, K, S>,
-        strings: Vec<&'static V>,
-        arena: Arena<V::Raw>,
-    ) -> Self {
-        Self {
-            map,
-            strings,
-            arena,
+    /// assert!(reader.has_prefix_index());
+    /// assert_eq!(vec![key], reader.keys_with_prefix("par").collect::<Vec<_>>());
+    /// ```
+    ///
+    /// [`keys_with_prefix`]: RodeoReader::keys_with_prefix
+    /// [`strings_with_prefix`]: RodeoReader::strings_with_prefix
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn build_prefix_index(&mut self) {
+        if self.prefix.is_none() {
+            self.prefix = Some(PrefixIndex::new(&self.table));
         }
     }
 
-    /// Get the key value of a string, returning `None` if it doesn't exist
+    /// Returns `true` if the prefix index has been built
     ///
     /// # Example
     ///
     /// ```rust
     /// use lasso::Rodeo;
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
-    /// let mut rodeo = Rodeo::default();
-    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
-    ///
-    /// let rodeo = rodeo.into_reader();
-    /// assert_eq!(Some(key), rodeo.get("Strings of things with wings and dings"));
+    /// let mut reader = Rodeo::default().into_reader();
+    /// assert!(!reader.has_prefix_index());
     ///
-    /// assert_eq!(None, rodeo.get("This string isn't interned"));
+    /// reader.build_prefix_index();
+    /// assert!(reader.has_prefix_index());
     /// ```
     ///
     #[inline]
-    pub fn get<T>(&self, val: T) -> Option<K>
-    where
-        T: AsRef<V>,
-    {
-        self.map.get(val.as_ref()).copied()
+    pub fn has_prefix_index(&self) -> bool {
+        self.prefix.is_some()
     }
 
-    /// Resolves a string by its key. Only keys made by the current Resolver or the creator
-    /// of the current Resolver may be used
+    /// Returns an iterator over the keys of every string starting with `prefix`, sorted by their strings.
+    /// Finding the strings takes `O(log n)` time, after which the iterator yields them lazily
     ///
     /// # Panics
     ///
-    /// Panics if the key is out of bounds
+    /// Panics if the index hasn't been built with [`build_prefix_index`]
     ///
     /// # Example
     ///
     /// ```rust
     /// use lasso::Rodeo;
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
     /// let mut rodeo = Rodeo::default();
-    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    /// let parse = rodeo.get_or_intern("parse");
+    /// let parent = rodeo.get_or_intern("parent");
+    /// rodeo.get_or_intern("print");
     ///
-    /// let rodeo = rodeo.into_resolver();
-    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve(&key));
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_prefix_index();
+    ///
+    /// assert_eq!(vec![parent, parse], reader.keys_with_prefix("par").collect::<Vec<_>>());
+    /// assert_eq!(None, reader.keys_with_prefix("pat").next());
     /// ```
     ///
-    /// [`Key`]: crate::Key
+    /// [`build_prefix_index`]: RodeoReader::build_prefix_index
     #[inline]
-    pub fn resolve<'a>(&'a self, key: &K) -> &'a V {
-        // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
-        // being symmetric and the caller having not fabricated a key. If the impl is sound
-        // and symmetric, then it will succeed, as the usize used to create it is a valid
-        // index into self.strings
-        unsafe {
-            assert!(key.into_usize() < self.strings.len());
-            self.strings.get_unchecked(key.into_usize())
-        }
+    pub fn keys_with_prefix(&self, prefix: &str) -> KeysWithPrefix<'_, K> {
+        let index = self
+            .prefix
+            .as_ref()
+            .expect("The prefix index has not been built");
+
+        // Safety: The index was built from the reader's strings
+        KeysWithPrefix::new(unsafe { index.with_prefix(&self.table, prefix) })
     }
 
-    /// Resolves a string by its key, returning `None` if the key is out of bounds. Only keys
-    /// made by the current Resolver or the creator of the current Resolver may be used
+    /// Returns an iterator over every string starting with `prefix` in sorted order.
+    /// Finding the strings takes `O(log n)` time, after which the iterator yields them lazily
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_prefix_index`]
     ///
     /// # Example
     ///
     /// ```rust
     /// use lasso::Rodeo;
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
     /// let mut rodeo = Rodeo::default();
-    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    /// rodeo.get_or_intern("parse");
+    /// rodeo.get_or_intern("parent");
+    /// rodeo.get_or_intern("print");
     ///
-    /// let rodeo = rodeo.into_resolver();
-    /// assert_eq!(Some("Strings of things with wings and dings"), rodeo.try_resolve(&key));
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_prefix_index();
+    ///
+    /// assert_eq!(vec!["parent", "parse"], reader.strings_with_prefix("par").collect::<Vec<_>>());
     /// ```
     ///
-    /// [`Key`]: crate::Key
+    /// [`build_prefix_index`]: RodeoReader::build_prefix_index
     #[inline]
-    pub fn try_resolve<'a>(&'a self, key: &K) -> Option<&'a V> {
-        // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
-        // being symmetric and the caller having not fabricated a key. If the impl is sound
-        // and symmetric, then it will succeed, as the usize used to create it is a valid
-        // index into self.strings
-        unsafe {
-            if key.into_usize() < self.strings.len() {
-                Some(self.strings.get_unchecked(key.into_usize()))
-            } else {
-                None
-            }
-        }
+    pub fn strings_with_prefix(&self, prefix: &str) -> StringsWithPrefix<'_, K> {
+        let index = self
+            .prefix
+            .as_ref()
+            .expect("The prefix index has not been built");
+
+        // Safety: The index was built from the reader's strings, so all of its keys are valid
+        unsafe { StringsWithPrefix::new(index.with_prefix(&self.table, prefix), &self.table) }
     }
 
-    /// Resolves a string by its key without bounds checks
+    /// Builds the index used by [`nearest`], a [BK-tree] over all interned strings that lets
+    /// strings within a small edit distance of another be found without comparing against
+    /// every string. Building the index again does nothing
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The key must be valid for the current Reader
+    /// Panics if the keys can hold strings within themselves like [`InlineSpur`], since those strings are never
+    /// stored and the index would miss them
     ///
     /// # Example
     ///
     /// ```rust
     /// use lasso::Rodeo;
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
     /// let mut rodeo = Rodeo::default();
-    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    /// let key = rodeo.get_or_intern("length");
     ///
-    /// let rodeo = rodeo.into_resolver();
-    /// unsafe {
-    ///     assert_eq!("Strings of things with wings and dings", rodeo.resolve_unchecked(&key));
-    /// }
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_nearest_index();
+    ///
+    /// assert!(reader.has_nearest_index());
+    /// assert_eq!(vec![(key, 2)], reader.nearest("lenght", 2).collect::<Vec<_>>());
     /// ```
     ///
-    /// [`Key`]: crate::Key
+    /// [`nearest`]: RodeoReader::nearest
+    /// [BK-tree]: https://en.wikipedia.org/wiki/BK-tree
+    /// [`InlineSpur`]: crate::InlineSpur
     #[inline]
-    pub unsafe fn resolve_unchecked<'a>(&'a self, key: &K) -> &'a V {
-        self.strings.get_unchecked(key.into_usize())
+    pub fn build_nearest_index(&mut self) {
+        if self.nearest.is_none() {
+            self.nearest = Some(BkTree::new(&self.table));
+        }
     }
 
-    /// Gets the number of interned strings
+    /// Returns `true` if the index used by [`nearest`] has been built
     ///
     /// # Example
     ///
     /// ```rust
     /// use lasso::Rodeo;
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
-    /// let mut rodeo = Rodeo::default();
-    /// rodeo.get_or_intern("Documentation often has little hidden bits in it");
+    /// let mut reader = Rodeo::default().into_reader();
+    /// assert!(!reader.has_nearest_index());
     ///
-    /// let rodeo = rodeo.into_reader();
-    /// assert_eq!(rodeo.len(), 1);
+    /// reader.build_nearest_index();
+    /// assert!(reader.has_nearest_index());
     /// ```
     ///
+    /// [`nearest`]: RodeoReader::nearest
     #[inline]
-    pub fn len(&self) -> usize {
-        self.strings.len()
+    pub fn has_nearest_index(&self) -> bool {
+        self.nearest.is_some()
     }
 
-    /// Returns `true` if there are no currently interned strings
+    /// Returns an iterator over the keys of every string at most `max_distance` edits away from `val`
+    /// along with their distances, ordered by distance and then by key. Distances are [Levenshtein distances]
+    /// counted in characters
+    ///
+    /// Small distances only visit a fraction of the strings, while large ones approach comparing
+    /// against every string
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_nearest_index`]
     ///
     /// # Example
     ///
     /// ```rust
     /// use lasso::Rodeo;
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
-    /// let rodeo = Rodeo::default();
+    /// let mut rodeo = Rodeo::default();
+    /// let width = rodeo.get_or_intern("width");
+    /// let with = rodeo.get_or_intern("with");
+    /// rodeo.get_or_intern("height");
     ///
-    /// let rodeo = rodeo.into_reader();
-    /// assert!(rodeo.is_empty());
+    /// let mut reader = rodeo.into_reader();
+    /// reader.build_nearest_index();
+    ///
+    /// // Did you mean `width`?
+    /// let mut suggestions = reader.nearest("widht", 2);
+    /// assert_eq!(Some((width, 2)), suggestions.next());
+    /// assert_eq!(Some((with, 2)), suggestions.next());
+    /// assert_eq!(None, suggestions.next());
     /// ```
     ///
+    /// [Levenshtein distances]: https://en.wikipedia.org/wiki/Levenshtein_distance
+    /// [`build_nearest_index`]: RodeoReader::build_nearest_index
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nearest(&self, val: &str, max_distance: usize) -> Nearest<K> {
+        let index = self
+            .nearest
+            .as_ref()
+            .expect("The nearest index has not been built");
+
+        // Safety: The index was built from the reader's strings
+        Nearest::new(unsafe { index.search(&self.table, val, max_distance) })
     }
 
-    /// Returns an iterator over the interned strings and their key values
+    /// Returns `true` if the RodeoReader stores a nul byte after every string, which is the case
+    /// when it was created from a nul terminated [`Rodeo`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// assert!(rodeo.into_reader().is_nul_terminated());
+    /// ```
+    ///
+    /// [`Rodeo`]: crate::Rodeo
     #[inline]
-    pub fn iter(&self) -> Iter<'_, V, K> {
-        Iter::from_reader(self)
+    pub fn is_nul_terminated(&self) -> bool {
+        self.table.is_terminated()
     }
 
-    /// Returns an iterator over the interned strings
+    /// Resolves a string by its key as a `CStr`, which costs nothing more than a normal resolution.
+    /// Only keys made by the current RodeoReader or the creator of the current RodeoReader may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds or if the RodeoReader isn't nul terminated
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let reader = rodeo.into_reader();
+    /// assert_eq!(
+    ///     b"Strings of things with wings and dings\0",
+    ///     reader.resolve_cstr(&key).to_bytes_with_nul(),
+    /// );
+    /// ```
+    ///
+    #[cfg(not(feature = "no-std"))]
     #[inline]
-    pub fn strings(&self) -> Strings<'_, V, K> {
-        Strings::from_reader(self)
+    pub fn resolve_cstr<'a>(&'a self, key: &K) -> &'a std::ffi::CStr {
+        assert!(
+            self.is_nul_terminated(),
+            "The RodeoReader is not nul terminated"
+        );
+
+        // Safety: The key is bounds checked and the table is nul terminated
+        unsafe {
+            assert!(key.into_usize() < self.table.len());
+
+            self.table.get_cstr_unchecked(key.into_usize())
+        }
     }
 
-    /// Consumes the current rodeo, making it into a [`RodeoResolver`], allowing
-    /// contention-free access from multiple threads with the lowest possible memory consumption
+    /// Resolves a string by its key as a `CStr`, returning `None` if it's out of bounds or if the
+    /// RodeoReader isn't nul terminated. Only keys made by the current RodeoReader or the creator of the
+    /// current RodeoReader may be used
     ///
     /// # Example
     ///
     /// ```rust
-    /// use lasso::Rodeo;
+    /// use lasso::{Rodeo, Spur};
     ///
-    /// // ThreadedRodeo is interchangeable for Rodeo here
-    /// let mut rodeo = Rodeo::default();
-    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
-    /// let reader_rodeo = rodeo.into_reader();
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
     ///
-    /// let resolver_rodeo = reader_rodeo.into_resolver();
+    /// let reader = rodeo.into_reader();
     /// assert_eq!(
-    ///     "Appear weak when you are strong, and strong when you are weak.",
-    ///     resolver_rodeo.resolve(&key),
+    ///     Some("Strings of things with wings and dings"),
+    ///     reader.try_resolve_cstr(&key).and_then(|cstr| cstr.to_str().ok()),
     /// );
     /// ```
     ///
-    /// [`RodeoResolver`]: crate::RodeoResolver
+    #[cfg(not(feature = "no-std"))]
     #[inline]
-    #[must_use]
-    pub fn into_resolver(mut self) -> RodeoResolver<V, K> {
-        self.map.drain().for_each(drop);
-
-        // Safety: The current reader no longer contains references to the strings
-        // in the vec given to RodeoResolver
-        unsafe { RodeoResolver::new(mem::take(&mut self.strings), mem::take(&mut self.arena)) }
+    pub fn try_resolve_cstr<'a>(&'a self, key: &K) -> Option<&'a std::ffi::CStr> {
+        // Safety: The key is bounds checked and the table is nul terminated
+        unsafe {
+            if self.is_nul_terminated() && key.into_usize() < self.table.len() {
+                Some(self.table.get_cstr_unchecked(key.into_usize()))
+            } else {
+                None
+            }
+        }
     }
 }
 
-/// Deallocate the leaked strings interned by RodeoReader
-impl<V, K, S> Drop for RodeoReader<V, K, S>
+/// Copies every string into a new buffer, cloning the map and indices since they only hold keys
+impl<V, K, S, N> Clone for RodeoReader<V, K, S, N>
 where
     V: Internable + ?Sized,
     K: Key,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
     #[inline]
-    fn drop(&mut self) {
-        // Safety: There must not be any other references to the strings in the arena, so
-        // all strings are drained before the arena can drop
-        self.strings.drain(..).for_each(drop);
+    fn clone(&self) -> Self {
+        let mut reader = Self::new(self.map.clone(), self.table.clone(), self.hashes.clone());
+        reader.prefix = self.prefix.clone();
+        reader.nearest = self.nearest.clone();
+        reader.bloom = self.bloom.clone();
+        reader.case_insensitive = self.case_insensitive.clone();
+
+        reader
     }
 }
 
-unsafe impl<V, K, S> Sync for RodeoReader<V, K, S>
+unsafe impl<V, K, S, N> Sync for RodeoReader<V, K, S, N>
 where
     V: Internable + ?Sized + Sync,
     K: Key + Sync,
     S: BuildHasher + Clone + Sync,
+    N: Normalizer<V>,
 {
 }
 
-unsafe impl<V, K, S> Send for RodeoReader<V, K, S>
+unsafe impl<V, K, S, N> Send for RodeoReader<V, K, S, N>
 where
     V: Internable + ?Sized + Send,
     K: Key + Send,
     S: BuildHasher + Clone + Send,
+    N: Normalizer<V>,
 {
 }
 
@@ -296,6 +1216,12 @@ mod tests {
     mod single_threaded {
         use crate::{single_threaded::Rodeo, Key, Spur};
 
+        compile! {
+            if #[feature = "no-std"] {
+                use alloc::{string::ToString, vec, vec::Vec};
+            }
+        }
+
         #[test]
         fn get() {
             let mut rodeo = Rodeo::default();
@@ -414,6 +1340,265 @@ mod tests {
             assert_eq!("A", resolver.resolve(&key));
         }
 
+        #[test]
+        fn case_insensitive() {
+            let mut rodeo = Rodeo::default();
+            let upper = rodeo.get_or_intern("STRASSE");
+            let title = rodeo.get_or_intern("Straße");
+            let lower = rodeo.get_or_intern("straße");
+            let sigma = rodeo.get_or_intern("ΟΔΟΣ");
+            let other = rodeo.get_or_intern("Other");
+
+            let mut reader = rodeo.into_reader();
+            assert!(!reader.has_case_insensitive_index());
+
+            reader.build_case_insensitive_index();
+            reader.build_case_insensitive_index();
+            assert!(reader.has_case_insensitive_index());
+
+            assert_eq!(&[title, lower], reader.get_all_case_insensitive("STRAẞE"));
+            assert_eq!(&[upper], reader.get_all_case_insensitive("strasse"));
+            assert_eq!(&[sigma], reader.get_all_case_insensitive("οδος"));
+            assert_eq!(&[sigma], reader.get_all_case_insensitive("οδοσ"));
+            assert_eq!(&[other], reader.get_all_case_insensitive("OTHER"));
+            assert!(reader.get_all_case_insensitive("missing").is_empty());
+
+            // The exact index is unaffected
+            assert_eq!(None, reader.get("other"));
+
+            let resolver = reader.into_resolver();
+            assert_eq!("Straße", resolver.resolve(&title));
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn case_insensitive_not_built() {
+            let reader = Rodeo::default().into_reader();
+            reader.get_all_case_insensitive("A");
+        }
+
+        #[test]
+        #[cfg(not(feature = "no-std"))]
+        fn resolve_cstr() {
+            let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+            let key = rodeo.get_or_intern("A");
+
+            let reader = rodeo.into_reader();
+            assert!(reader.is_nul_terminated());
+            assert_eq!(Some(key), reader.get("A"));
+            assert_eq!(b"A\0", reader.resolve_cstr(&key).to_bytes_with_nul());
+            assert_eq!(
+                None,
+                reader.try_resolve_cstr(&Spur::try_from_usize(10).unwrap())
+            );
+
+            let resolver = reader.into_resolver();
+            assert!(resolver.is_nul_terminated());
+            assert_eq!(b"A\0", resolver.resolve_cstr(&key).to_bytes_with_nul());
+
+            let mut rodeo = Rodeo::default();
+            let key = rodeo.get_or_intern("A");
+            let reader = rodeo.into_reader();
+            assert!(!reader.is_nul_terminated());
+            assert_eq!(None, reader.try_resolve_cstr(&key));
+        }
+
+        #[test]
+        fn prefix_index() {
+            let mut rodeo = Rodeo::default();
+            let parse = rodeo.get_or_intern("parse");
+            let print = rodeo.get_or_intern("print");
+            let parent = rodeo.get_or_intern("parent");
+            let par = rodeo.get_or_intern("par");
+
+            let mut reader = rodeo.into_reader();
+            assert!(!reader.has_prefix_index());
+            reader.build_prefix_index();
+            assert!(reader.has_prefix_index());
+
+            assert_eq!(
+                vec![par, parent, parse],
+                reader.keys_with_prefix("par").collect::<Vec<_>>()
+            );
+            assert_eq!(
+                vec!["print", "parse", "parent", "par"],
+                reader.strings_with_prefix("p").rev().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                vec![print],
+                reader.keys_with_prefix("pr").collect::<Vec<_>>()
+            );
+            assert_eq!(0, reader.keys_with_prefix("q").len());
+
+            let cloned = reader.clone();
+            assert!(cloned.has_prefix_index());
+            assert_eq!(
+                vec!["par", "parent", "parse"],
+                cloned.strings_with_prefix("par").collect::<Vec<_>>()
+            );
+
+            let resolver = reader.into_resolver();
+            assert!(resolver.has_prefix_index());
+            assert_eq!(
+                vec![par, parent, parse],
+                resolver.keys_with_prefix("pa").collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn nearest_index() {
+            let mut rodeo = Rodeo::default();
+            let length = rodeo.get_or_intern("length");
+            let width = rodeo.get_or_intern("width");
+            let height = rodeo.get_or_intern("height");
+            let len = rodeo.get_or_intern("len");
+
+            let mut reader = rodeo.into_reader();
+            assert!(!reader.has_nearest_index());
+            reader.build_nearest_index();
+            assert!(reader.has_nearest_index());
+
+            assert_eq!(
+                vec![(length, 0), (len, 3)],
+                reader.nearest("length", 3).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                vec![(height, 1)],
+                reader.nearest("heigt", 1).collect::<Vec<_>>()
+            );
+            assert_eq!(0, reader.nearest("xyz", 2).len());
+            assert_eq!(4, reader.nearest("", 6).len());
+
+            let cloned = reader.clone();
+            drop(reader);
+            assert_eq!(
+                vec![(width, 1)],
+                cloned.nearest("widh", 1).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn bloom_filter() {
+            let mut rodeo = Rodeo::default();
+            let keys = (0..1000)
+                .map(|i| rodeo.get_or_intern(i.to_string()))
+                .collect::<Vec<_>>();
+
+            let mut reader = rodeo.into_reader();
+            assert!(!reader.has_bloom_filter());
+            assert_eq!(None, reader.bloom_filter_size());
+
+            reader.build_bloom_filter(0.01);
+            assert!(reader.has_bloom_filter());
+
+            for (i, key) in keys.iter().enumerate() {
+                let string = i.to_string();
+
+                assert_eq!(Some(*key), reader.get(&string));
+                assert_eq!(
+                    Some(*key),
+                    reader.get_with_hash(&string, reader.hash_one(&string))
+                );
+            }
+
+            for i in 1000..2000 {
+                assert_eq!(None, reader.get(i.to_string()));
+            }
+
+            let cloned = reader.clone();
+            assert!(cloned.has_bloom_filter());
+            assert_eq!(Some(keys[10]), cloned.get("10"));
+        }
+
+        #[test]
+        fn bloom_filter_normalized() {
+            let mut rodeo: Rodeo<str, Spur, _, crate::AsciiCaseInsensitive> =
+                Rodeo::with_normalizer();
+            let key = rodeo.get_or_intern("Hello");
+
+            let reader = rodeo.into_reader_with_bloom_filter(0.001);
+            assert_eq!(Some(key), reader.get("hELLO"));
+            assert_eq!(None, reader.get("World"));
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn bloom_filter_invalid_rate() {
+            Rodeo::default().into_reader().build_bloom_filter(0.0);
+        }
+
+        #[test]
+        fn share_suffixes() {
+            let mut rodeo = Rodeo::default();
+            let owner = rodeo.get_or_intern("lasso::reader");
+            let suffix = rodeo.get_or_intern("reader");
+            let other = rodeo.get_or_intern("resolver");
+            let empty = rodeo.get_or_intern("");
+
+            let mut reader = rodeo.into_reader();
+            reader.build_case_insensitive_index();
+            reader.build_bloom_filter(0.01);
+            assert_eq!("reader".len(), reader.share_suffixes());
+
+            let (owner_str, suffix_str) = (reader.resolve(&owner), reader.resolve(&suffix));
+            assert_eq!("reader", suffix_str);
+            assert_eq!(owner_str[7..].as_ptr(), suffix_str.as_ptr());
+
+            for (key, string) in &[
+                (owner, "lasso::reader"),
+                (suffix, "reader"),
+                (other, "resolver"),
+                (empty, ""),
+            ] {
+                assert_eq!(*string, reader.resolve(key));
+                assert_eq!(Some(*key), reader.get(string));
+            }
+            assert_eq!(None, reader.get("lasso"));
+
+            assert_eq!(&[suffix], reader.get_all_case_insensitive("READER"));
+            assert!(reader.has_bloom_filter());
+
+            let cloned = reader.clone();
+            drop(reader);
+            assert_eq!("reader", cloned.resolve(&suffix));
+            assert_eq!(&[owner], cloned.get_all_case_insensitive("Lasso::Reader"));
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn nearest_index_not_built() {
+            let reader = Rodeo::default().into_reader();
+            reader.nearest("a", 1);
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn prefix_index_inline_keys() {
+            let mut rodeo: Rodeo<str, crate::InlineSpur> = Rodeo::new();
+            rodeo.get_or_intern("Hello");
+            rodeo.into_reader().build_prefix_index();
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn rank_table_inline_keys() {
+            let rodeo: Rodeo<str, crate::InlineSpur> = Rodeo::new();
+            rodeo.into_reader().rank_table();
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn prefix_index_not_built() {
+            let reader = Rodeo::default().into_reader();
+            reader.keys_with_prefix("a");
+        }
+
         #[test]
         #[cfg(not(any(feature = "no-std", feature = "ahasher")))]
         fn debug() {
@@ -422,7 +1607,7 @@ mod tests {
         }
     }
 
-    #[cfg(all(not(any(miri, feature = "no-std")), features = "multi-threaded"))]
+    #[cfg(all(not(any(miri, feature = "no-std")), feature = "multi-threaded"))]
     mod multi_threaded {
         use crate::{locks::Arc, multi_threaded::ThreadedRodeo};
 
@@ -431,7 +1616,7 @@ mod tests {
         #[test]
         fn get() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let reader = rodeo.into_reader();
             assert_eq!(Some(key), reader.get("A"));
@@ -443,7 +1628,7 @@ mod tests {
         #[cfg(not(miri))]
         fn get_threaded() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let reader = Arc::new(rodeo.into_reader());
 
@@ -460,7 +1645,7 @@ mod tests {
         #[test]
         fn resolve() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let reader = rodeo.into_reader();
             assert_eq!("A", reader.resolve(&key));
@@ -470,7 +1655,7 @@ mod tests {
         #[cfg(not(miri))]
         fn resolve_threaded() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let reader = Arc::new(rodeo.into_reader());
 
@@ -485,9 +1670,9 @@ mod tests {
         #[test]
         fn len() {
             let rodeo = ThreadedRodeo::default();
-            rodeo.intern("A");
-            rodeo.intern("B");
-            rodeo.intern("C");
+            rodeo.get_or_intern("A");
+            rodeo.get_or_intern("B");
+            rodeo.get_or_intern("C");
 
             let reader = rodeo.into_reader();
             assert_eq!(reader.len(), 3);
@@ -504,7 +1689,7 @@ mod tests {
         #[test]
         fn clone() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("Test");
+            let key = rodeo.get_or_intern("Test");
 
             let reader_rodeo = rodeo.into_reader();
             assert_eq!("Test", reader_rodeo.resolve(&key));
@@ -570,7 +1755,7 @@ mod tests {
         #[test]
         fn into_resolver() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let resolver = rodeo.into_reader().into_resolver();
             assert_eq!("A", resolver.resolve(&key));
@@ -0,0 +1,126 @@
+use crate::key::Key;
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::vec::Vec;
+    }
+}
+
+/// A mapping from the keys of one interner to the keys the same strings were given by another,
+/// returned by conversions that reassign keys such as [`Rodeo::into_sorted_reader`]
+///
+/// [`Rodeo::into_sorted_reader`]: crate::Rodeo::into_sorted_reader
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRemap<K: Key> {
+    keys: Keys<K>,
+}
+
+/// The keys of a [`KeyRemap`], stored by whichever of the old or new keys are contiguous
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Keys<K> {
+    /// The new key of every old key, indexed by the old key
+    Dense(Vec<K>),
+    /// The old keys in order when they weren't given out contiguously, where the new key of each is
+    /// its position
+    #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+    Sparse(Vec<K>),
+}
+
+impl<K: Key> KeyRemap<K> {
+    #[inline]
+    pub(crate) fn new(keys: Vec<K>) -> Self {
+        Self {
+            keys: Keys::Dense(keys),
+        }
+    }
+
+    /// Creates a remap from old keys that weren't given out contiguously, which must be in order and are
+    /// given the new keys counting up from zero
+    #[inline]
+    #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+    pub(crate) fn with_gaps(keys: Vec<K>) -> Self {
+        Self {
+            keys: Keys::Sparse(keys),
+        }
+    }
+
+    /// Gets the new key of an old key
+    ///
+    /// # Panics
+    ///
+    /// Panics if the old key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// assert_eq!(
+    ///     "Strings of things with wings and dings",
+    ///     reader.resolve(&remap.remap(&key)),
+    /// );
+    /// ```
+    ///
+    #[inline]
+    pub fn remap(&self, old: &K) -> K {
+        self.get(old)
+            .expect("The key doesn't belong to the remapped interner")
+    }
+
+    /// Gets the new key of an old key, returning `None` if the old key is out of bounds. Keys that
+    /// hold their string within themselves are the same in every interner, so they're returned as is
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Key, Rodeo, Spur};
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// assert_eq!(Some(reader.get("Strings of things with wings and dings").unwrap()), remap.get(&key));
+    /// assert_eq!(None, remap.get(&Spur::try_from_usize(10).unwrap()));
+    /// ```
+    ///
+    #[inline]
+    pub fn get(&self, old: &K) -> Option<K> {
+        if old.is_inline() {
+            return Some(*old);
+        }
+
+        // Safety: The index is bounds checked, so a fabricated key can only fail to be found
+        let idx = unsafe { old.into_usize() };
+
+        match &self.keys {
+            Keys::Dense(keys) => keys.get(idx).copied(),
+
+            // Safety: The old keys were made by an interner, so their indices are in bounds
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Keys::Sparse(keys) => keys
+                .binary_search_by_key(&idx, |key| unsafe { key.into_usize() })
+                .ok()
+                .and_then(K::try_from_usize),
+        }
+    }
+
+    /// Gets the number of old keys
+    #[inline]
+    pub fn len(&self) -> usize {
+        match &self.keys {
+            Keys::Dense(keys) => keys.len(),
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Keys::Sparse(keys) => keys.len(),
+        }
+    }
+
+    /// Returns `true` if there are no old keys
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
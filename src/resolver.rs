@@ -1,15 +1,19 @@
 use crate::{
-    arena::Arena,
+    front_coded::FrontCodedResolver,
     internable::Internable,
     key::{Key, Spur},
+    prefix::{KeysWithPrefix, PrefixIndex, StringsWithPrefix},
+    rank::RankTable,
+    substring::{glob_matches, longest_literal, SubstringIndex},
+    table::StringTable,
     util::{Iter, Strings},
 };
 
-use core::marker::PhantomData;
+use core::{marker::PhantomData, num::NonZeroUsize};
 
 compile! {
     if #[feature = "no-std"] {
-        use alloc::vec::Vec;
+        use alloc::{string::String, vec::Vec};
     }
 }
 
@@ -26,10 +30,12 @@ where
     V: Internable + ?Sized,
     K: Key,
 {
-    /// Vector of strings mapped to key indexes that allows key to string resolution
-    pub(crate) strings: Vec<&'static V>,
-    /// The arena that contains all the strings
-    arena: Arena<V::Raw>,
+    /// The strings indexed by key, allowing key to string resolution
+    pub(crate) table: StringTable<V>,
+    /// The optional prefix index, holding every key sorted by its string
+    pub(crate) prefix: Option<PrefixIndex<K>>,
+    /// The optional substring index, a sparse suffix array of all the strings
+    substring: Option<SubstringIndex<K>>,
     /// The type of the key
     __key: PhantomData<K>,
 }
@@ -40,16 +46,12 @@ where
     K: Key,
 {
     /// Creates a new RodeoResolver
-    ///
-    /// # Safety
-    ///
-    /// The references inside of `strings` must be absolutely unique, meaning
-    /// that no other references to those strings exist
-    ///
-    pub(crate) unsafe fn new(strings: Vec<&'static V>, arena: Arena<V::Raw>) -> Self {
+    #[inline]
+    pub(crate) fn new(table: StringTable<V>) -> Self {
         Self {
-            strings,
-            arena,
+            table,
+            prefix: None,
+            substring: None,
             __key: PhantomData,
         }
     }
@@ -80,11 +82,11 @@ where
         // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
         // being symmetric and the caller having not fabricated a key. If the impl is sound
         // and symmetric, then it will succeed, as the usize used to create it is a valid
-        // index into self.strings
+        // index into self.table
         unsafe {
-            assert!(key.into_usize() < self.strings.len());
+            assert!(key.into_usize() < self.table.len());
 
-            self.strings.get_unchecked(key.into_usize())
+            self.table.get_unchecked(key.into_usize())
         }
     }
 
@@ -110,21 +112,15 @@ where
         // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
         // being symmetric and the caller having not fabricated a key. If the impl is sound
         // and symmetric, then it will succeed, as the usize used to create it is a valid
-        // index into self.strings
-        unsafe {
-            if key.into_usize() < self.strings.len() {
-                Some(self.strings.get_unchecked(key.into_usize()))
-            } else {
-                None
-            }
-        }
+        // index into self.table
+        unsafe { self.table.get(key.into_usize()) }
     }
 
     /// Resolves a string by its key without bounds checking
     ///
     /// # Safety
     ///
-    /// The key must be valid for the current interner
+    /// The key must be valid for the current interner and can't hold its string within itself, see [`Key::is_inline`]
     ///
     /// # Example
     ///
@@ -144,7 +140,7 @@ where
     /// [`Key`]: crate::Key
     #[inline]
     pub unsafe fn resolve_unchecked<'a>(&'a self, key: &K) -> &'a V {
-        self.strings.get_unchecked(key.into_usize())
+        self.table.get_unchecked(key.into_usize())
     }
 
     /// Gets the number of interned strings
@@ -164,7 +160,7 @@ where
     ///
     #[inline]
     pub fn len(&self) -> usize {
-        self.strings.len()
+        self.table.len()
     }
 
     /// Returns `true` if there are no currently interned strings
@@ -199,17 +195,599 @@ where
     }
 }
 
-/// Deallocate the leaked strings interned by RodeoResolver
-impl<V, K> Drop for RodeoResolver<V, K>
+impl<K> RodeoResolver<str, K>
+where
+    K: Key,
+{
+    /// Resolves a string by its key into the given buffer, replacing its contents. Unlike [`resolve`], this
+    /// also resolves keys that hold their string within themselves, see [`InlineSpur`]. Only keys made by the
+    /// current RodeoResolver or the creator of the current RodeoResolver may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let resolver = rodeo.into_resolver();
+    /// let mut buf = String::new();
+    /// assert_eq!("+", resolver.resolve_into(&plus, &mut buf));
+    /// assert_eq!("Strings of things with wings and dings", resolver.resolve_into(&long, &mut buf));
+    /// ```
+    ///
+    /// [`resolve`]: RodeoResolver::resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> &'b str {
+        self.try_resolve_into(key, buf).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents, or returns `None` and
+    /// leaves the buffer alone if the key is out of bounds. Unlike [`try_resolve`], this also resolves keys
+    /// that hold their string within themselves, see [`InlineSpur`]. Only keys made by the current
+    /// RodeoResolver or the creator of the current RodeoResolver may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let resolver = rodeo.into_resolver();
+    /// let mut buf = String::new();
+    /// assert_eq!(Some("+"), resolver.try_resolve_into(&plus, &mut buf));
+    /// assert_eq!(None, resolver.try_resolve_into(&InlineSpur::try_from_usize(10).unwrap(), &mut buf));
+    /// ```
+    ///
+    /// [`try_resolve`]: RodeoResolver::try_resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn try_resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> Option<&'b str> {
+        let string = match key.as_inline::<str>() {
+            Some(string) => string,
+            None => self.try_resolve(key)?,
+        };
+
+        buf.clear();
+        buf.push_str(string);
+
+        Some(buf.as_str())
+    }
+
+    /// Consumes the current resolver, coding its strings into a [`FrontCodedResolver`] that stores
+    /// `block_size` strings per block and keeps the same keys. Any indexes are dropped
+    ///
+    /// See [`FrontCodedResolver`] for how the block size trades memory for speed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("crate::module::Type::method");
+    ///
+    /// let resolver = rodeo.into_resolver().into_front_coded(16);
+    /// assert_eq!("crate::module::Type::method", resolver.resolve(&key));
+    /// ```
+    ///
+    /// [`FrontCodedResolver`]: crate::FrontCodedResolver
+    #[inline]
+    #[must_use]
+    pub fn into_front_coded(self, block_size: usize) -> FrontCodedResolver<K> {
+        let block_size = NonZeroUsize::new(block_size).expect("The block size must be at least 1");
+
+        FrontCodedResolver::new(&self.table, block_size)
+    }
+
+    /// Builds the prefix index used by [`keys_with_prefix`] and [`strings_with_prefix`], which sorts
+    /// the keys of all interned strings so that strings sharing a prefix can be found with a binary
+    /// search. Building the index again does nothing
+    ///
+    /// An index built by a [`RodeoReader`] is kept when it's turned into a RodeoResolver
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys can hold strings within themselves like [`InlineSpur`], since those strings are never
+    /// stored and the index would miss them
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("parse");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// resolver.build_prefix_index();
+    ///
+    /// assert!(resolver.has_prefix_index());
+    /// assert_eq!(vec![key], resolver.keys_with_prefix("par").collect::<Vec<_>>());
+    /// ```
+    ///
+    /// [`keys_with_prefix`]: RodeoResolver::keys_with_prefix
+    /// [`strings_with_prefix`]: RodeoResolver::strings_with_prefix
+    /// [`RodeoReader`]: crate::RodeoReader
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn build_prefix_index(&mut self) {
+        if self.prefix.is_none() {
+            self.prefix = Some(PrefixIndex::new(&self.table));
+        }
+    }
+
+    /// Returns `true` if the prefix index has been built
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut resolver = Rodeo::default().into_resolver();
+    /// assert!(!resolver.has_prefix_index());
+    ///
+    /// resolver.build_prefix_index();
+    /// assert!(resolver.has_prefix_index());
+    /// ```
+    ///
+    #[inline]
+    pub fn has_prefix_index(&self) -> bool {
+        self.prefix.is_some()
+    }
+
+    /// Returns an iterator over the keys of every string starting with `prefix`, sorted by their strings.
+    /// Finding the strings takes `O(log n)` time, after which the iterator yields them lazily
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_prefix_index`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let parse = rodeo.get_or_intern("parse");
+    /// let parent = rodeo.get_or_intern("parent");
+    /// rodeo.get_or_intern("print");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// resolver.build_prefix_index();
+    ///
+    /// assert_eq!(vec![parent, parse], resolver.keys_with_prefix("par").collect::<Vec<_>>());
+    /// assert_eq!(None, resolver.keys_with_prefix("pat").next());
+    /// ```
+    ///
+    /// [`build_prefix_index`]: RodeoResolver::build_prefix_index
+    #[inline]
+    pub fn keys_with_prefix(&self, prefix: &str) -> KeysWithPrefix<'_, K> {
+        let index = self
+            .prefix
+            .as_ref()
+            .expect("The prefix index has not been built");
+
+        // Safety: The index was built from the resolver's strings
+        KeysWithPrefix::new(unsafe { index.with_prefix(&self.table, prefix) })
+    }
+
+    /// Returns an iterator over every string starting with `prefix` in sorted order.
+    /// Finding the strings takes `O(log n)` time, after which the iterator yields them lazily
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_prefix_index`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("parse");
+    /// rodeo.get_or_intern("parent");
+    /// rodeo.get_or_intern("print");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// resolver.build_prefix_index();
+    ///
+    /// assert_eq!(vec!["parent", "parse"], resolver.strings_with_prefix("par").collect::<Vec<_>>());
+    /// ```
+    ///
+    /// [`build_prefix_index`]: RodeoResolver::build_prefix_index
+    #[inline]
+    pub fn strings_with_prefix(&self, prefix: &str) -> StringsWithPrefix<'_, K> {
+        let index = self
+            .prefix
+            .as_ref()
+            .expect("The prefix index has not been built");
+
+        // Safety: The index was built from the resolver's strings, so all of its keys are valid
+        unsafe { StringsWithPrefix::new(index.with_prefix(&self.table, prefix), &self.table) }
+    }
+
+    /// Builds the substring index used by [`find_containing`] and [`find_glob`], a sparse suffix array
+    /// holding the position of every `stride`th character of every string. Building the index again
+    /// with a different stride replaces it
+    ///
+    /// The stride trades memory for the shortest needle the index can find. The index takes
+    /// `(size_of::<K>() + 4) * chars / stride` bytes (rounded up per string), where `chars` is the
+    /// total number of characters of all strings, so a stride of 1 is a full suffix array that takes
+    /// 8 bytes per character for [`Spur`]s. Needles shorter than `stride` characters fall back to
+    /// scanning every string, and [`substring_index_size`] reports the exact size of the index
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is 0, if a string is longer than `u32::MAX` bytes, or if the keys can hold strings
+    /// within themselves like [`InlineSpur`], since those strings are never stored and the index would miss them
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("RequestHandler");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// resolver.build_substring_index(3);
+    ///
+    /// assert!(resolver.has_substring_index());
+    /// assert_eq!(vec![key], resolver.find_containing("Handler"));
+    /// ```
+    ///
+    /// [`find_containing`]: RodeoResolver::find_containing
+    /// [`find_glob`]: RodeoResolver::find_glob
+    /// [`substring_index_size`]: RodeoResolver::substring_index_size
+    /// [`Spur`]: crate::Spur
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn build_substring_index(&mut self, stride: usize) {
+        let stride = NonZeroUsize::new(stride).expect("The stride must be at least 1");
+
+        if !matches!(&self.substring, Some(index) if index.stride() == stride.get()) {
+            self.substring = Some(SubstringIndex::new(&self.table, stride));
+        }
+    }
+
+    /// Returns `true` if the substring index has been built
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut resolver = Rodeo::default().into_resolver();
+    /// assert!(!resolver.has_substring_index());
+    ///
+    /// resolver.build_substring_index(1);
+    /// assert!(resolver.has_substring_index());
+    /// ```
+    ///
+    #[inline]
+    pub fn has_substring_index(&self) -> bool {
+        self.substring.is_some()
+    }
+
+    /// Returns the number of bytes used by the substring index, or `None` if it hasn't been built
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("abcdefgh");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// assert_eq!(None, resolver.substring_index_size());
+    ///
+    /// resolver.build_substring_index(1);
+    /// let full = resolver.substring_index_size().unwrap();
+    ///
+    /// resolver.build_substring_index(4);
+    /// assert_eq!(full / 4, resolver.substring_index_size().unwrap());
+    /// ```
+    ///
+    #[inline]
+    pub fn substring_index_size(&self) -> Option<usize> {
+        self.substring.as_ref().map(SubstringIndex::size_in_bytes)
+    }
+
+    /// Gets the keys of every string containing `needle`, sorted by key
+    ///
+    /// Needles with at least as many characters as the index's stride are found with a binary search
+    /// for each indexed position they could cover, shorter ones are found by scanning every string
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_substring_index`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let request = rodeo.get_or_intern("RequestHandler");
+    /// let error = rodeo.get_or_intern("ErrorHandlerTest");
+    /// rodeo.get_or_intern("handle");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// resolver.build_substring_index(2);
+    ///
+    /// assert_eq!(vec![request, error], resolver.find_containing("Handler"));
+    /// assert_eq!(vec![error], resolver.find_containing("rH"));
+    /// assert!(resolver.find_containing("Handlers").is_empty());
+    /// ```
+    ///
+    /// [`build_substring_index`]: RodeoResolver::build_substring_index
+    #[inline]
+    pub fn find_containing(&self, needle: &str) -> Vec<K> {
+        let index = self
+            .substring
+            .as_ref()
+            .expect("The substring index has not been built");
+
+        // Safety: The index was built from the resolver's strings
+        unsafe { index.containing(&self.table, needle) }
+            .unwrap_or_else(|| self.scan(|string| string.contains(needle)))
+    }
+
+    /// Gets the keys of every string matching the glob `pattern`, sorted by key. A `*` matches
+    /// any number of characters, a `?` matches exactly one character, and everything else
+    /// matches itself
+    ///
+    /// The longest run of literal characters in the pattern is searched for with the substring index
+    /// and its matches are then checked against the whole pattern, so patterns whose literals are all
+    /// shorter than the index's stride scan every string
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index hasn't been built with [`build_substring_index`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let parse = rodeo.get_or_intern("parse_test");
+    /// rodeo.get_or_intern("parse_tests");
+    /// let print = rodeo.get_or_intern("print_test");
+    ///
+    /// let mut resolver = rodeo.into_resolver();
+    /// resolver.build_substring_index(2);
+    ///
+    /// assert_eq!(vec![parse, print], resolver.find_glob("*_test"));
+    /// assert_eq!(vec![parse], resolver.find_glob("pa?se_*t"));
+    /// ```
+    ///
+    /// [`build_substring_index`]: RodeoResolver::build_substring_index
+    #[inline]
+    pub fn find_glob(&self, pattern: &str) -> Vec<K> {
+        let index = self
+            .substring
+            .as_ref()
+            .expect("The substring index has not been built");
+
+        // Safety: The index was built from the resolver's strings
+        match unsafe { index.containing(&self.table, longest_literal(pattern)) } {
+            Some(mut keys) => {
+                // Safety: The keys were made by the index, so they're all valid
+                keys.retain(|key| glob_matches(pattern, unsafe { self.resolve_unchecked(key) }));
+                keys
+            }
+            None => self.scan(|string| glob_matches(pattern, string)),
+        }
+    }
+
+    /// Gets the keys of every string that satisfies `predicate` by checking all of them
+    #[inline]
+    fn scan<F>(&self, mut predicate: F) -> Vec<K>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.iter()
+            .filter(|(_, string)| predicate(string))
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Returns `true` if the RodeoResolver stores a nul byte after every string, which is the case
+    /// when it was created from a nul terminated [`Rodeo`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// assert!(rodeo.into_resolver().is_nul_terminated());
+    /// ```
+    ///
+    /// [`Rodeo`]: crate::Rodeo
+    #[inline]
+    pub fn is_nul_terminated(&self) -> bool {
+        self.table.is_terminated()
+    }
+
+    /// Resolves a string by its key as a `CStr`, which costs nothing more than a normal resolution.
+    /// Only keys made by the current RodeoResolver or the creator of the current RodeoResolver may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds or if the RodeoResolver isn't nul terminated
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let resolver = rodeo.into_resolver();
+    /// assert_eq!(
+    ///     b"Strings of things with wings and dings\0",
+    ///     resolver.resolve_cstr(&key).to_bytes_with_nul(),
+    /// );
+    /// ```
+    ///
+    #[cfg(not(feature = "no-std"))]
+    #[inline]
+    pub fn resolve_cstr<'a>(&'a self, key: &K) -> &'a std::ffi::CStr {
+        assert!(
+            self.is_nul_terminated(),
+            "The RodeoResolver is not nul terminated"
+        );
+
+        // Safety: The key is bounds checked and the table is nul terminated
+        unsafe {
+            assert!(key.into_usize() < self.table.len());
+
+            self.table.get_cstr_unchecked(key.into_usize())
+        }
+    }
+
+    /// Resolves a string by its key as a `CStr`, returning `None` if it's out of bounds or if the
+    /// RodeoResolver isn't nul terminated. Only keys made by the current RodeoResolver or the creator of the
+    /// current RodeoResolver may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let resolver = rodeo.into_resolver();
+    /// assert_eq!(
+    ///     Some("Strings of things with wings and dings"),
+    ///     resolver.try_resolve_cstr(&key).and_then(|cstr| cstr.to_str().ok()),
+    /// );
+    /// ```
+    ///
+    #[cfg(not(feature = "no-std"))]
+    #[inline]
+    pub fn try_resolve_cstr<'a>(&'a self, key: &K) -> Option<&'a std::ffi::CStr> {
+        // Safety: The key is bounds checked and the table is nul terminated
+        unsafe {
+            if self.is_nul_terminated() && key.into_usize() < self.table.len() {
+                Some(self.table.get_cstr_unchecked(key.into_usize()))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl<V, K> RodeoResolver<V, K>
+where
+    V: Internable + Ord + ?Sized,
+    K: Key,
+{
+    /// Ranks every key by its string, creating a [`RankTable`] that compares and sorts keys in
+    /// lexicographic order without resolving them
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `u32::MAX` strings, or if the keys can hold strings within themselves
+    /// like [`InlineSpur`], since those strings are never stored and can't be ranked
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let mut keys = vec![rodeo.get_or_intern("b"), rodeo.get_or_intern("a")];
+    ///
+    /// let rodeo = rodeo.into_resolver();
+    /// let ranks = rodeo.rank_table();
+    /// assert!(ranks.rank(&keys[1]) < ranks.rank(&keys[0]));
+    ///
+    /// ranks.sort_keys_by_str(&mut keys);
+    /// assert_eq!("a", rodeo.resolve(&keys[0]));
+    /// ```
+    ///
+    /// [`RankTable`]: crate::RankTable
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn rank_table(&self) -> RankTable<K> {
+        RankTable::new(&self.table)
+    }
+}
+
+impl<V, K> RodeoResolver<V, K>
+where
+    V: Internable + ?Sized,
+    V::Raw: Ord,
+    K: Key,
+{
+    /// Stores every string that's a suffix of another interned string as the tail of that string instead
+    /// of on its own, returning the number of bytes saved compared to storing every string on its own.
+    /// Keys are unaffected and strings are still resolved in `O(1)`
+    ///
+    /// All strings are copied into a single new buffer, so sharing suffixes takes about as long as cloning
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// // ThreadedRodeo is interchangeable for Rodeo here
+    /// let mut rodeo = Rodeo::default();
+    /// let path = rodeo.get_or_intern("src/module/file.rs");
+    /// let file = rodeo.get_or_intern("file.rs");
+    ///
+    /// let mut rodeo = rodeo.into_resolver();
+    /// assert_eq!("file.rs".len(), rodeo.share_suffixes());
+    ///
+    /// assert_eq!("src/module/file.rs", rodeo.resolve(&path));
+    /// assert_eq!("file.rs", rodeo.resolve(&file));
+    /// ```
+    ///
+    #[inline]
+    pub fn share_suffixes(&mut self) -> usize {
+        let (table, saved) = self.table.share_suffixes();
+        self.table = table;
+
+        saved
+    }
+}
+
+/// Copies every string into a new buffer
+impl<V, K> Clone for RodeoResolver<V, K>
 where
     V: Internable + ?Sized,
     K: Key,
 {
     #[inline]
-    fn drop(&mut self) {
-        // Safety: There must not be any other references to the strings in the arena, so
-        // all strings are drained before the arena can drop
-        self.strings.drain(..).for_each(drop);
+    fn clone(&self) -> Self {
+        let mut resolver = Self::new(self.table.clone());
+        resolver.prefix = self.prefix.clone();
+        resolver.substring = self.substring.clone();
+
+        resolver
     }
 }
 
@@ -232,6 +810,12 @@ mod tests {
     mod single_threaded {
         use crate::{single_threaded::Rodeo, Key, Spur};
 
+        compile! {
+            if #[feature = "no-std"] {
+                use alloc::{vec, vec::Vec};
+            }
+        }
+
         #[test]
         fn resolve() {
             let mut rodeo = Rodeo::default();
@@ -330,6 +914,156 @@ mod tests {
             assert_eq!(None, iter.next());
         }
 
+        #[test]
+        #[cfg(not(feature = "no-std"))]
+        fn resolve_cstr() {
+            let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+            let key = rodeo.get_or_intern("A");
+
+            let resolver = rodeo.into_resolver();
+            assert!(resolver.is_nul_terminated());
+            assert_eq!(b"A\0", resolver.resolve_cstr(&key).to_bytes_with_nul());
+            assert_eq!(
+                None,
+                resolver.try_resolve_cstr(&Spur::try_from_usize(10).unwrap())
+            );
+
+            let mut rodeo = Rodeo::default();
+            let key = rodeo.get_or_intern("A");
+            let resolver = rodeo.into_resolver();
+            assert!(!resolver.is_nul_terminated());
+            assert_eq!(None, resolver.try_resolve_cstr(&key));
+        }
+
+        #[test]
+        fn share_suffixes() {
+            let mut rodeo = Rodeo::default();
+            let c = rodeo.get_or_intern("c");
+            let abc = rodeo.get_or_intern("a.b.c");
+            let bc = rodeo.get_or_intern("b.c");
+            let x = rodeo.get_or_intern("x");
+
+            let mut resolver = rodeo.into_resolver();
+            assert_eq!("b.c".len() + "c".len(), resolver.share_suffixes());
+            // Sharing again saves just as much compared to storing every string on its own
+            assert_eq!("b.c".len() + "c".len(), resolver.share_suffixes());
+
+            assert_eq!("a.b.c", resolver.resolve(&abc));
+            assert_eq!("b.c", resolver.resolve(&bc));
+            assert_eq!("c", resolver.resolve(&c));
+            assert_eq!("x", resolver.resolve(&x));
+
+            let end = resolver.resolve(&abc).as_bytes().as_ptr_range().end;
+            assert_eq!(end, resolver.resolve(&bc).as_bytes().as_ptr_range().end);
+            assert_eq!(end, resolver.resolve(&c).as_bytes().as_ptr_range().end);
+
+            assert_eq!(0, Rodeo::default().into_resolver().share_suffixes());
+        }
+
+        #[test]
+        #[cfg(not(feature = "no-std"))]
+        fn share_suffixes_nul_terminated() {
+            let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+            let long = rodeo.get_or_intern("long");
+            let ong = rodeo.get_or_intern("ong");
+            let lo = rodeo.get_or_intern("lo");
+
+            let mut resolver = rodeo.into_resolver();
+            assert_eq!("ong".len(), resolver.share_suffixes());
+
+            assert_eq!(b"long\0", resolver.resolve_cstr(&long).to_bytes_with_nul());
+            assert_eq!(b"ong\0", resolver.resolve_cstr(&ong).to_bytes_with_nul());
+            assert_eq!(b"lo\0", resolver.resolve_cstr(&lo).to_bytes_with_nul());
+        }
+
+        #[test]
+        fn prefix_index() {
+            let mut rodeo = Rodeo::default();
+            let b = rodeo.get_or_intern("b");
+            let ab = rodeo.get_or_intern("ab");
+            let a = rodeo.get_or_intern("a");
+
+            let mut resolver = rodeo.into_resolver();
+            resolver.build_prefix_index();
+
+            assert_eq!(
+                vec![a, ab],
+                resolver.keys_with_prefix("a").collect::<Vec<_>>()
+            );
+            assert_eq!(vec![b], resolver.keys_with_prefix("b").collect::<Vec<_>>());
+            assert_eq!(
+                vec!["a", "ab", "b"],
+                resolver.strings_with_prefix("").collect::<Vec<_>>()
+            );
+            assert_eq!(None, resolver.strings_with_prefix("c").next());
+
+            let cloned = resolver.clone();
+            drop(resolver);
+            assert_eq!(
+                vec!["a", "ab"],
+                cloned.strings_with_prefix("a").collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn substring_index() {
+            let mut rodeo = Rodeo::default();
+            let request = rodeo.get_or_intern("RequestHandler");
+            let handle = rodeo.get_or_intern("handle");
+            let test = rodeo.get_or_intern("handler_test");
+            let empty = rodeo.get_or_intern("");
+
+            let mut resolver = rodeo.into_resolver();
+            for stride in 1..=4 {
+                resolver.build_substring_index(stride);
+
+                assert_eq!(vec![request], resolver.find_containing("Handler"));
+                assert_eq!(vec![handle, test], resolver.find_containing("handle"));
+                assert_eq!(vec![request, handle, test], resolver.find_containing("and"));
+                assert_eq!(vec![test], resolver.find_containing("_"));
+                assert_eq!(
+                    vec![request, handle, test, empty],
+                    resolver.find_containing("")
+                );
+
+                assert_eq!(vec![test], resolver.find_glob("*_test"));
+                assert_eq!(vec![request, test], resolver.find_glob("*?andler*"));
+                assert_eq!(vec![handle], resolver.find_glob("handle"));
+                assert_eq!(vec![empty], resolver.find_glob(""));
+                assert_eq!(vec![request, handle, test, empty], resolver.find_glob("*"));
+                assert!(resolver.find_glob("*_tests").is_empty());
+            }
+
+            let cloned = resolver.clone();
+            drop(resolver);
+            assert!(cloned.has_substring_index());
+            assert_eq!(vec![request], cloned.find_containing("quest"));
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn substring_index_not_built() {
+            let resolver = Rodeo::default().into_resolver();
+            resolver.find_containing("a");
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn substring_index_zero_stride() {
+            let mut resolver = Rodeo::default().into_resolver();
+            resolver.build_substring_index(0);
+        }
+
+        #[test]
+        #[should_panic]
+        #[cfg(not(miri))]
+        fn prefix_index_not_built() {
+            let resolver = Rodeo::default().into_resolver();
+            resolver.strings_with_prefix("a");
+        }
+
         #[test]
         #[cfg(not(feature = "no-std"))]
         fn debug() {
@@ -338,15 +1072,15 @@ mod tests {
         }
     }
 
-    #[cfg(all(not(any(miri, feature = "no-std")), features = "multi-threaded"))]
+    #[cfg(all(not(any(miri, feature = "no-std")), feature = "multi-threaded"))]
     mod multi_threaded {
-        use crate::{locks::Arc, multi_threaded::ThreadedRodeo};
+        use crate::{locks::Arc, multi_threaded::ThreadedRodeo, Key, Spur};
         use std::thread;
 
         #[test]
         fn resolve() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let resolver = rodeo.into_resolver();
             assert_eq!("A", resolver.resolve(&key));
@@ -354,8 +1088,8 @@ mod tests {
 
         #[test]
         fn try_resolve() {
-            let mut rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let rodeo = ThreadedRodeo::default();
+            let key = rodeo.get_or_intern("A");
 
             let resolver = rodeo.into_resolver();
             assert_eq!(Some("A"), resolver.try_resolve(&key));
@@ -369,17 +1103,14 @@ mod tests {
         #[cfg(not(miri))]
         fn try_resolve_threaded() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let resolver = Arc::new(rodeo.into_resolver());
 
             let moved = Arc::clone(&resolver);
             thread::spawn(move || {
-                assert_eq!(Some("A"), resolver.try_resolve(&key));
-                assert_eq!(
-                    None,
-                    resolver.try_resolve(&Spur::try_from_usize(10).unwrap())
-                );
+                assert_eq!(Some("A"), moved.try_resolve(&key));
+                assert_eq!(None, moved.try_resolve(&Spur::try_from_usize(10).unwrap()));
             });
 
             assert_eq!(Some("A"), resolver.try_resolve(&key));
@@ -391,7 +1122,7 @@ mod tests {
 
         #[test]
         fn resolve_unchecked() {
-            let mut rodeo = ThreadedRodeo::default();
+            let rodeo = ThreadedRodeo::default();
             let a = rodeo.get_or_intern("A");
 
             let resolver = rodeo.into_resolver();
@@ -404,17 +1135,17 @@ mod tests {
         #[cfg(not(miri))]
         fn resolve_unchecked_threaded() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let resolver = Arc::new(rodeo.into_resolver());
 
             let moved = Arc::clone(&resolver);
             thread::spawn(move || unsafe {
-                assert_eq!("A", moved.resolve_unchecked(&a));
+                assert_eq!("A", moved.resolve_unchecked(&key));
             });
 
             unsafe {
-                assert_eq!("A", resolver.resolve_unchecked(&a));
+                assert_eq!("A", resolver.resolve_unchecked(&key));
             }
         }
 
@@ -422,7 +1153,7 @@ mod tests {
         #[cfg(not(miri))]
         fn resolve_threaded() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("A");
+            let key = rodeo.get_or_intern("A");
 
             let resolver = Arc::new(rodeo.into_resolver());
 
@@ -437,9 +1168,9 @@ mod tests {
         #[test]
         fn len() {
             let rodeo = ThreadedRodeo::default();
-            rodeo.intern("A");
-            rodeo.intern("B");
-            rodeo.intern("C");
+            rodeo.get_or_intern("A");
+            rodeo.get_or_intern("B");
+            rodeo.get_or_intern("C");
 
             let resolver = rodeo.into_resolver();
             assert_eq!(resolver.len(), 3);
@@ -488,7 +1219,7 @@ mod tests {
         #[test]
         fn clone() {
             let rodeo = ThreadedRodeo::default();
-            let key = rodeo.intern("Test");
+            let key = rodeo.get_or_intern("Test");
 
             let resolver_rodeo = rodeo.into_resolver();
             assert_eq!("Test", resolver_rodeo.resolve(&key));
@@ -0,0 +1,347 @@
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    mem::MaybeUninit,
+    ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering},
+};
+
+/// The number of slots in the first segment is `1 << FIRST_SEGMENT_SHIFT`, and every segment after it
+/// is twice as large as the one before it
+const FIRST_SEGMENT_SHIFT: u32 = 5;
+const FIRST_SEGMENT_LEN: usize = 1 << FIRST_SEGMENT_SHIFT;
+
+/// The number of segments needed to hold every index below `usize::MAX - FIRST_SEGMENT_LEN`
+const NUM_SEGMENTS: usize = (usize::BITS - FIRST_SEGMENT_SHIFT) as usize;
+
+/// An append-only vector whose values are written once each from any thread and read without locking,
+/// used to resolve the keys of a `ThreadedRodeo`
+///
+/// Values live in segments that double in size and are never moved once allocated, so a reference to
+/// a segment stays valid while others are added. Each slot is published with a release store to its flag
+/// after its value is written, so a reader that sees the flag with an acquire load also sees the value.
+/// Indices can be left unwritten, but the length only counts the published indices that come one after
+/// another from the start, so every index less than a length that's been observed can be read. Writers
+/// never wait on each other, the length is found by [`len`] scanning the flags past the last length found
+///
+/// [`len`]: SegmentedVec::len
+pub(crate) struct SegmentedVec<T: Copy> {
+    /// Pointers to the segments, where the segment at `idx` holds `FIRST_SEGMENT_LEN << idx` slots or is null
+    /// if it hasn't been allocated yet
+    segments: [AtomicPtr<Slot<T>>; NUM_SEGMENTS],
+    /// The last length found by [`len`], every index below it is published
+    ///
+    /// [`len`]: SegmentedVec::len
+    len: AtomicUsize,
+}
+
+/// A single value of a [`SegmentedVec`] along with whether it's been published
+struct Slot<T> {
+    ready: AtomicBool,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T: Copy> SegmentedVec<T> {
+    /// Creates an empty vector that doesn't allocate
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            segments: core::array::from_fn(|_| AtomicPtr::new(ptr::null_mut())),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Creates an empty vector with enough segments allocated to hold `capacity` values
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        let vec = Self::new();
+        if capacity != 0 {
+            if let Some((last, _)) = locate(capacity - 1) {
+                (0..=last).for_each(|segment| {
+                    vec.segment(segment);
+                });
+            }
+        }
+
+        vec
+    }
+
+    /// Gets the value at an index, returning `None` if it's out of bounds or hasn't been published yet
+    #[inline]
+    pub(crate) fn get(&self, idx: usize) -> Option<T> {
+        let (segment, offset) = locate(idx)?;
+
+        let slots = self.segments[segment].load(Ordering::Acquire);
+        if slots.is_null() {
+            return None;
+        }
+
+        // Safety: The segment is allocated and the offset is within its length
+        let slot = unsafe { &*slots.add(offset) };
+        if slot.ready.load(Ordering::Acquire) {
+            // Safety: The value was written before the slot was published
+            Some(unsafe { (*slot.value.get()).assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Writes and publishes the value at an index, allocating its segment if needed
+    ///
+    /// The value can be read as soon as it's published, but isn't counted by the length of the vector
+    /// until every index below it has been published too
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is within the last `FIRST_SEGMENT_LEN` indices of a `usize`
+    ///
+    /// # Safety
+    ///
+    /// Each index can only be written once, and only by one thread
+    ///
+    #[inline]
+    pub(crate) unsafe fn write(&self, idx: usize, value: T) {
+        let (segment, offset) = locate(idx).expect("Index out of the vector's bounds");
+        let slot = &*self.segment(segment).add(offset);
+
+        // Safety: Nothing reads the value until it's published, and the caller guarantees that
+        // nothing else writes it
+        (*slot.value.get()).as_mut_ptr().write(value);
+        slot.ready.store(true, Ordering::Release);
+    }
+
+    /// Gets the number of published indices that come one after another from the start, where every index
+    /// below it can be read
+    ///
+    /// Only the slots past the last length that was found are checked, so each slot is only scanned by the
+    /// calls that race to find it
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        let known = self.len.load(Ordering::Acquire);
+
+        let mut len = known;
+        while self.get(len).is_some() {
+            len += 1;
+        }
+
+        if len != known {
+            self.len.fetch_max(len, Ordering::AcqRel);
+        }
+
+        len
+    }
+
+    /// Gets the number of values that can be written without allocating another segment
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.segments
+            .iter()
+            .enumerate()
+            .take_while(|(_, segment)| !segment.load(Ordering::Acquire).is_null())
+            .map(|(segment, _)| segment_len(segment))
+            .sum()
+    }
+
+    /// Gets the slots of a segment, allocating them if they haven't been yet
+    #[inline]
+    fn segment(&self, segment: usize) -> *mut Slot<T> {
+        let slots = self.segments[segment].load(Ordering::Acquire);
+        if !slots.is_null() {
+            return slots;
+        }
+
+        // Allocate the segment and try to publish it, using the segment another thread published
+        // first if there is one
+        let new = Box::into_raw(
+            (0..segment_len(segment))
+                .map(|_| Slot {
+                    ready: AtomicBool::new(false),
+                    value: UnsafeCell::new(MaybeUninit::uninit()),
+                })
+                .collect::<Box<[Slot<T>]>>(),
+        ) as *mut Slot<T>;
+
+        match self.segments[segment].compare_exchange(
+            ptr::null_mut(),
+            new,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(existing) => {
+                // Safety: The new segment was never published, so nothing else refers to it
+                unsafe { free_segment(new, segment) };
+                existing
+            }
+        }
+    }
+}
+
+impl<T: Copy> Drop for SegmentedVec<T> {
+    #[inline]
+    fn drop(&mut self) {
+        for (segment, slots) in self.segments.iter_mut().enumerate() {
+            let slots = *slots.get_mut();
+
+            if !slots.is_null() {
+                // Safety: The segment was allocated with its length and nothing can refer to it anymore.
+                // Values are `Copy`, so they don't need to be dropped
+                unsafe { free_segment(slots, segment) };
+            }
+        }
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for SegmentedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.capacity()).filter_map(|idx| self.get(idx)))
+            .finish()
+    }
+}
+
+// Safety: Values are only shared between threads by copying them out, and each slot is written once
+// before it's published
+unsafe impl<T: Copy + Send> Send for SegmentedVec<T> {}
+unsafe impl<T: Copy + Send + Sync> Sync for SegmentedVec<T> {}
+
+/// Gets the segment an index is in and its offset within the segment, returning `None` if it's past
+/// the last segment
+#[inline]
+fn locate(idx: usize) -> Option<(usize, usize)> {
+    // Offsetting the index by the first segment's length makes each segment start at a power of two
+    let biased = idx.checked_add(FIRST_SEGMENT_LEN)?;
+    let segment = (usize::BITS - 1 - biased.leading_zeros() - FIRST_SEGMENT_SHIFT) as usize;
+
+    Some((segment, biased - (FIRST_SEGMENT_LEN << segment)))
+}
+
+/// Gets the number of slots in a segment
+#[inline]
+fn segment_len(segment: usize) -> usize {
+    FIRST_SEGMENT_LEN << segment
+}
+
+/// Frees the slots of a segment
+///
+/// # Safety
+///
+/// The slots must have been allocated for the segment and nothing else can refer to them
+///
+#[inline]
+unsafe fn free_segment<T>(slots: *mut Slot<T>, segment: usize) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+        slots,
+        segment_len(segment),
+    )));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(miri))]
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn locate_segments() {
+        assert_eq!(Some((0, 0)), locate(0));
+        assert_eq!(Some((0, 31)), locate(31));
+        assert_eq!(Some((1, 0)), locate(32));
+        assert_eq!(Some((1, 63)), locate(95));
+        assert_eq!(Some((2, 0)), locate(96));
+        assert_eq!(
+            Some((NUM_SEGMENTS - 1, segment_len(NUM_SEGMENTS - 1) - 1)),
+            locate(usize::MAX - FIRST_SEGMENT_LEN)
+        );
+        assert_eq!(None, locate(usize::MAX));
+    }
+
+    #[test]
+    fn write_and_get() {
+        let vec = SegmentedVec::new();
+        assert_eq!(0, vec.capacity());
+        assert_eq!(None, vec.get(0));
+
+        for idx in 0..1000 {
+            unsafe { vec.write(idx, idx * 2) };
+        }
+
+        assert_eq!(1000, vec.len());
+        assert!(vec.capacity() >= 1000);
+        assert!((0..1000).all(|idx| vec.get(idx) == Some(idx * 2)));
+        assert_eq!(None, vec.get(1000));
+    }
+
+    #[test]
+    fn unpublished_slots() {
+        let vec = SegmentedVec::new();
+        unsafe { vec.write(100, 'a') };
+
+        assert_eq!(Some('a'), vec.get(100));
+        assert_eq!(None, vec.get(0));
+        assert_eq!(None, vec.get(99));
+        assert_eq!(0, vec.len());
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    fn len_skips_unpublished_slots() {
+        let vec = Arc::new(SegmentedVec::new());
+
+        let later = {
+            let vec = Arc::clone(&vec);
+            thread::spawn(move || unsafe { vec.write(1, 'b') })
+        };
+        later.join().unwrap();
+
+        // The later value is readable right away, but the length doesn't cover it until the index
+        // before it is published
+        assert_eq!(Some('b'), vec.get(1));
+        assert_eq!(0, vec.len());
+
+        unsafe { vec.write(0, 'a') };
+        assert_eq!(2, vec.len());
+        assert_eq!(Some('a'), vec.get(0));
+
+        unsafe { vec.write(2, 'c') };
+        assert_eq!(3, vec.len());
+    }
+
+    #[test]
+    fn with_capacity() {
+        assert_eq!(0, SegmentedVec::<u8>::with_capacity(0).capacity());
+        assert_eq!(32, SegmentedVec::<u8>::with_capacity(1).capacity());
+        assert_eq!(32, SegmentedVec::<u8>::with_capacity(32).capacity());
+        assert_eq!(96, SegmentedVec::<u8>::with_capacity(33).capacity());
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    fn concurrent_writes() {
+        let vec = Arc::new(SegmentedVec::new());
+
+        let threads = (0..4)
+            .map(|thread| {
+                let vec = Arc::clone(&vec);
+
+                thread::spawn(move || {
+                    for idx in (thread..4000).step_by(4) {
+                        unsafe { vec.write(idx, idx) };
+                        assert_eq!(Some(idx), vec.get(idx));
+
+                        // Every index the length covers must be readable
+                        let len = vec.len();
+                        assert!((0..len).all(|idx| vec.get(idx) == Some(idx)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        threads
+            .into_iter()
+            .for_each(|thread| thread.join().unwrap());
+
+        assert_eq!(4000, vec.len());
+        assert!((0..4000).all(|idx| vec.get(idx) == Some(idx)));
+    }
+}
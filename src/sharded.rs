@@ -0,0 +1,329 @@
+use crate::{
+    key::Key,
+    util::{find_key, KeyMap},
+};
+
+use core::{fmt, ops::Range};
+use hashbrown::hash_map::RawEntryMut;
+use std::sync::{OnceLock, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// The concurrent map `ThreadedRodeo` uses to find the keys of strings, other maps can be swapped in here
+/// behind cargo features without changing its public API
+pub(crate) type DefaultKeyMap<K> = ShardedKeyMap<K>;
+
+/// A concurrent map from strings to their keys that's shared by every thread of a `ThreadedRodeo`
+///
+/// Like the maps of `Rodeo` and `RodeoReader` it only holds keys, so strings are compared through the
+/// interner that owns it and every lookup uses a hash that was computed once up front by the interner.
+/// Maps split into shards pick a string's shard with the same hash
+pub(crate) trait ConcurrentKeyMap<K: Key>: Sized {
+    /// Creates an empty map that can hold at least `capacity` keys before reallocating, split into
+    /// `shard_amount` shards if it's sharded
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shard_amount` isn't a power of two
+    ///
+    fn with_capacity_and_shard_amount(capacity: usize, shard_amount: usize) -> Self;
+
+    /// Gets the number of shards the map is split into, which is one if it isn't sharded
+    fn shard_amount(&self) -> usize;
+
+    /// Finds the key of a string by its hash, where `is_match` compares the string of a key's index
+    /// to the string being looked up
+    fn get<F>(&self, hash: u64, is_match: F) -> Option<K>
+    where
+        F: FnMut(usize) -> bool;
+
+    /// Finds the key of a string by its hash like [`get`], creating it with `make_key` if it's not in the map
+    ///
+    /// `make_key` must only be called once for every string even if multiple threads try to insert it at
+    /// the same time, and the key it returns can't be found by any other thread until it returns.
+    /// `hash_of` gets the hash of a key that's already in the map so that keys can be moved when it grows
+    ///
+    /// Every shard keeps a block of indices that its new keys are taken from, which is given to `make_key`
+    /// while only it can use the block so that the interner can reserve indices in batches, along with the
+    /// number of keys in the shard so that it can size the blocks it reserves
+    ///
+    /// [`get`]: ConcurrentKeyMap::get
+    fn get_or_insert_with<F, M, H>(&self, hash: u64, is_match: F, make_key: M, hash_of: H) -> Option<K>
+    where
+        F: FnMut(usize) -> bool,
+        M: FnOnce(&mut Range<usize>, usize) -> Option<K>,
+        H: Fn(&K) -> u64;
+
+    /// Gets the number of keys in the map
+    fn len(&self) -> usize;
+
+    /// Removes every key from the map along with the indices reserved by its shards
+    fn clear(&self);
+}
+
+/// The default [`ConcurrentKeyMap`], split into shards that are each a hash map behind its own `RwLock`
+pub(crate) struct ShardedKeyMap<K> {
+    shards: Box<[RwLock<Shard<K>>]>,
+    /// The number of bits of a hash used to pick its shard
+    shift: u32,
+}
+
+/// A single shard of a [`ShardedKeyMap`]
+struct Shard<K> {
+    keys: KeyMap<K, ()>,
+    /// The indices reserved for the shard's new keys that haven't been used yet
+    block: Range<usize>,
+}
+
+impl<K> ShardedKeyMap<K>
+where
+    K: Key,
+{
+    /// Gets the shard that holds the keys of strings with the given hash
+    #[inline]
+    fn shard(&self, hash: u64) -> &RwLock<Shard<K>> {
+        // The top seven bits of a hash are used by each shard to tell its entries apart and the lowest bits
+        // pick their buckets, so the shard is picked with the bits directly below the top seven
+        let shard = (hash >> (57 - self.shift)) as usize & (self.shards.len() - 1);
+
+        // Safety: The index is masked to the number of shards, which is a power of two
+        unsafe { self.shards.get_unchecked(shard) }
+    }
+}
+
+impl<K> ConcurrentKeyMap<K> for ShardedKeyMap<K>
+where
+    K: Key,
+{
+    #[inline]
+    fn with_capacity_and_shard_amount(capacity: usize, shard_amount: usize) -> Self {
+        assert!(
+            shard_amount.is_power_of_two() && shard_amount <= MAX_SHARD_AMOUNT,
+            "The shard amount must be a power of two no larger than {}",
+            MAX_SHARD_AMOUNT,
+        );
+        let per_shard = capacity.div_ceil(shard_amount);
+
+        Self {
+            shards: (0..shard_amount)
+                .map(|_| {
+                    RwLock::new(Shard {
+                        keys: KeyMap::with_capacity_and_hasher(per_shard, ()),
+                        block: 0..0,
+                    })
+                })
+                .collect(),
+            shift: shard_amount.trailing_zeros(),
+        }
+    }
+
+    #[inline]
+    fn shard_amount(&self) -> usize {
+        self.shards.len()
+    }
+
+    #[inline]
+    fn get<F>(&self, hash: u64, is_match: F) -> Option<K>
+    where
+        F: FnMut(usize) -> bool,
+    {
+        find_key(&read(self.shard(hash)).keys, hash, is_match)
+    }
+
+    #[inline]
+    fn get_or_insert_with<F, M, H>(
+        &self,
+        hash: u64,
+        mut is_match: F,
+        make_key: M,
+        hash_of: H,
+    ) -> Option<K>
+    where
+        F: FnMut(usize) -> bool,
+        M: FnOnce(&mut Range<usize>, usize) -> Option<K>,
+        H: Fn(&K) -> u64,
+    {
+        // The shard stays locked while the key is made, so no other thread can insert the same string
+        let mut shard = write(self.shard(hash));
+        let Shard { keys, block } = &mut *shard;
+        let len = keys.len();
+
+        // Safety: The index is only given to `is_match`, which bounds checks it or gets it from a key in the map
+        match keys
+            .raw_entry_mut()
+            .from_hash(hash, |key| is_match(unsafe { key.into_usize() }))
+        {
+            RawEntryMut::Occupied(entry) => Some(*entry.key()),
+            RawEntryMut::Vacant(entry) => {
+                let key = make_key(block, len)?;
+                entry.insert_with_hasher(hash, key, (), hash_of);
+
+                Some(key)
+            }
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.shards.iter().map(|shard| read(shard).keys.len()).sum()
+    }
+
+    #[inline]
+    fn clear(&self) {
+        self.shards.iter().for_each(|shard| {
+            let mut shard = write(shard);
+            shard.keys.clear();
+            shard.block = 0..0;
+        });
+    }
+}
+
+/// Locks a shard for reading
+///
+/// A shard is only changed after the key of a new string has been made, so one whose lock was poisoned by
+/// a panic while making a key is still whole and can be used
+#[inline]
+fn read<K>(shard: &RwLock<Shard<K>>) -> RwLockReadGuard<'_, Shard<K>> {
+    shard.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Locks a shard for writing, see [`read`] for why poisoned locks are used anyway
+#[inline]
+fn write<K>(shard: &RwLock<Shard<K>>) -> RwLockWriteGuard<'_, Shard<K>> {
+    shard.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<K> fmt::Debug for ShardedKeyMap<K>
+where
+    K: Key + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.shards.iter().map(read).flat_map(|shard| {
+                shard.keys.keys().copied().collect::<Vec<_>>()
+            }))
+            .finish()
+    }
+}
+
+/// The most shards a map can be split into, since the bits of a hash that pick its shard can't overlap
+/// the top seven bits
+pub(crate) const MAX_SHARD_AMOUNT: usize = 1 << 16;
+
+/// Gets the number of shards maps are split into unless another amount is given, which is a power of two
+/// of at least four shards per thread. The number of threads is only asked for once and then cached
+#[inline]
+pub(crate) fn default_shard_amount() -> usize {
+    static SHARD_AMOUNT: OnceLock<usize> = OnceLock::new();
+
+    *SHARD_AMOUNT.get_or_init(|| {
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+
+        (threads * 4).next_power_of_two().min(MAX_SHARD_AMOUNT)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Spur;
+
+    fn key(idx: usize) -> Spur {
+        Spur::try_from_usize(idx).unwrap()
+    }
+
+    #[test]
+    fn get_or_insert() {
+        let hashes = [u64::MAX, 0, 1 << 60];
+        let map = ShardedKeyMap::<Spur>::with_capacity_and_shard_amount(0, 4);
+
+        for (idx, &hash) in hashes.iter().enumerate() {
+            assert_eq!(None, map.get(hash, |i| i == idx));
+
+            let inserted = map.get_or_insert_with(
+                hash,
+                |i| i == idx,
+                |_, _| Some(key(idx)),
+                |key| hashes[unsafe { key.into_usize() }],
+            );
+            assert_eq!(Some(key(idx)), inserted);
+        }
+
+        for (idx, &hash) in hashes.iter().enumerate() {
+            assert_eq!(Some(key(idx)), map.get(hash, |i| i == idx));
+            assert_eq!(
+                Some(key(idx)),
+                map.get_or_insert_with(hash, |i| i == idx, |_, _| unreachable!(), |_| unreachable!()),
+            );
+        }
+
+        map.clear();
+        assert_eq!(None, map.get(hashes[0], |i| i == 0));
+    }
+
+    #[test]
+    fn reserved_blocks() {
+        let map = ShardedKeyMap::<Spur>::with_capacity_and_shard_amount(0, 1);
+        let hashes = [1, 2, 3];
+
+        // The block a shard reserved is kept between insertions
+        for (idx, &hash) in hashes.iter().enumerate() {
+            let inserted = map.get_or_insert_with(
+                hash,
+                |i| i == idx,
+                |block, len| {
+                    assert_eq!(idx, len);
+                    if *block == (0..0) {
+                        *block = 10..12;
+                    }
+                    block.next().map(key)
+                },
+                |key| hashes[unsafe { key.into_usize() } - 10],
+            );
+
+            assert_eq!([Some(key(10)), Some(key(11)), None][idx], inserted);
+        }
+        assert_eq!(2, map.len());
+
+        map.clear();
+        assert_eq!(0, map.len());
+    }
+
+    #[test]
+    fn failed_insert() {
+        let map = ShardedKeyMap::<Spur>::with_capacity_and_shard_amount(10, 1);
+
+        assert_eq!(None, map.get_or_insert_with(0, |_| true, |_, _| None, |_| 0));
+        assert_eq!(None, map.get(0, |_| true));
+    }
+
+    #[test]
+    fn shard_amount() {
+        assert!(default_shard_amount().is_power_of_two());
+        assert!(default_shard_amount() >= 4);
+        assert_eq!(default_shard_amount(), default_shard_amount());
+
+        for &amount in &[1, 2, 64, MAX_SHARD_AMOUNT] {
+            let map = ShardedKeyMap::<Spur>::with_capacity_and_shard_amount(0, amount);
+            assert_eq!(amount, map.shard_amount());
+
+            let hashes = [0, u64::MAX, 1 << 56, 1 << 40];
+            for (idx, &hash) in hashes.iter().enumerate() {
+                map.get_or_insert_with(
+                    hash,
+                    |i| i == idx,
+                    |_, _| Some(key(idx)),
+                    |key| hashes[unsafe { key.into_usize() }],
+                );
+            }
+            for (idx, &hash) in hashes.iter().enumerate() {
+                assert_eq!(Some(key(idx)), map.get(hash, |i| i == idx));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn invalid_shard_amount() {
+        ShardedKeyMap::<Spur>::with_capacity_and_shard_amount(0, 3);
+    }
+}
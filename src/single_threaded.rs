@@ -1,40 +1,53 @@
 use crate::{
-    arena::Arena,
-    hasher::{HashMap, RandomState},
-    internable::Internable,
+    hasher::RandomState,
+    internable::{AsInternable, Internable},
     key::{Key, Spur},
+    normalizer::{Identity, Normalized, Normalizer},
     reader::RodeoReader,
+    remap::KeyRemap,
     resolver::RodeoResolver,
-    util::{Iter, Strings},
+    sorted::SortedReader,
+    table::{StringTable, Terminator},
+    utf16::{EncodeUtf16, Utf16Str},
+    util::{find_key, hash_of, inline_key, Iter, KeyMap, Strings},
 };
 
-use core::{hash::BuildHasher, mem};
+use core::{hash::BuildHasher, marker::PhantomData};
+use hashbrown::hash_map::RawEntryMut;
 
 compile! {
     if #[feature = "no-std"] {
-        use alloc::vec::Vec;
+        use alloc::{string::String, vec::Vec};
     }
 }
 
 /// A string interner that caches strings quickly with a minimal memory footprint,
 /// returning a unique key to re-access it with `O(1)` internment and resolution.
 ///
-/// By default Rodeo uses the [`Spur`] type for keys and [`RandomState`] as its hasher
+/// By default Rodeo uses the [`Spur`] type for keys, [`RandomState`] as its hasher and
+/// compares strings exactly, which can be changed with a [`Normalizer`]
 ///
 /// [`RandomState`]: index.html#cargo-features
+/// [`Normalizer`]: crate::Normalizer
 #[derive(Debug)]
-pub struct Rodeo<V = str, K = Spur, S = RandomState>
+pub struct Rodeo<V = str, K = Spur, S = RandomState, N = Identity>
 where
     V: Internable + ?Sized,
     K: Key,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
-    /// Map that allows `str` -> `key` resolution
-    map: HashMap<&'static V, K, S>,
-    /// Vec that allows `key` -> `str` resolution
-    pub(crate) strings: Vec<&'static V>,
-    /// The arena that holds all allocated strings
-    arena: Arena<V::Raw>,
+    /// Map that allows `str` -> `key` resolution, holding only keys that are compared through `table`
+    map: KeyMap<K, S>,
+    /// Table that allows `key` -> `str` resolution, holding every string back to back so that it can be
+    /// handed to a [`RodeoReader`] or [`RodeoResolver`] as is
+    ///
+    /// [`RodeoReader`]: crate::RodeoReader
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    pub(crate) table: StringTable<V>,
+    /// The hash of every string, used to grow the map and to move strings between maps without rehashing
+    hashes: Vec<u64>,
+    __normalizer: PhantomData<N>,
 }
 
 impl<V, K> Rodeo<V, K, RandomState>
@@ -60,9 +73,10 @@ where
     #[inline]
     pub fn new() -> Self {
         Self {
-            map: HashMap::with_hasher(RandomState::new()),
-            strings: Vec::new(),
-            arena: Arena::default(),
+            map: KeyMap::with_hasher(RandomState::new()),
+            table: StringTable::default(),
+            hashes: Vec::new(),
+            __normalizer: PhantomData,
         }
     }
 
@@ -80,9 +94,10 @@ where
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            map: HashMap::with_capacity_and_hasher(capacity, RandomState::new()),
-            strings: Vec::with_capacity(capacity),
-            arena: Arena::default(),
+            map: KeyMap::with_capacity_and_hasher(capacity, RandomState::new()),
+            table: StringTable::with_capacity(capacity, None),
+            hashes: Vec::with_capacity(capacity),
+            __normalizer: PhantomData,
         }
     }
 }
@@ -107,9 +122,10 @@ where
     #[inline]
     pub fn with_hasher(hash_builder: S) -> Self {
         Self {
-            map: HashMap::with_hasher(hash_builder),
-            strings: Vec::new(),
-            arena: Arena::default(),
+            map: KeyMap::with_hasher(hash_builder),
+            table: StringTable::default(),
+            hashes: Vec::new(),
+            __normalizer: PhantomData,
         }
     }
 
@@ -127,9 +143,73 @@ where
     #[inline]
     pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
         Self {
-            map: HashMap::with_capacity_and_hasher(capacity, hash_builder),
-            strings: Vec::with_capacity(capacity),
-            arena: Arena::default(),
+            map: KeyMap::with_capacity_and_hasher(capacity, hash_builder),
+            table: StringTable::with_capacity(capacity, None),
+            hashes: Vec::with_capacity(capacity),
+            __normalizer: PhantomData,
+        }
+    }
+}
+
+impl<V, K, N> Rodeo<V, K, RandomState, N>
+where
+    V: Internable + ?Sized,
+    K: Key,
+    N: Normalizer<V>,
+{
+    /// Creates an empty Rodeo which will use the [`Normalizer`] `N` to decide which strings are the same
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{AsciiCaseInsensitive, Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur, _, AsciiCaseInsensitive> = Rodeo::with_normalizer();
+    /// let key = rodeo.get_or_intern("Hello");
+    ///
+    /// assert_eq!(Some(key), rodeo.get("HELLO"));
+    /// assert_eq!("Hello", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    #[inline]
+    pub fn with_normalizer() -> Self {
+        Self::with_capacity_hasher_and_normalizer(0, RandomState::new())
+    }
+}
+
+impl<V, K, S, N> Rodeo<V, K, S, N>
+where
+    V: Internable + ?Sized,
+    K: Key,
+    S: BuildHasher + Clone,
+    N: Normalizer<V>,
+{
+    /// Creates a new Rodeo with the specified capacity that will use the given hasher for its internal
+    /// hashmap and the [`Normalizer`] `N` to decide which strings are the same
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{AsciiLowercase, Rodeo, Spur};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let mut rodeo: Rodeo<str, Spur, _, AsciiLowercase> =
+    ///     Rodeo::with_capacity_hasher_and_normalizer(10, RandomState::new());
+    /// let key = rodeo.get_or_intern("Hello");
+    ///
+    /// assert_eq!(Some(key), rodeo.get("HELLO"));
+    /// assert_eq!("hello", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    #[inline]
+    pub fn with_capacity_hasher_and_normalizer(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            map: KeyMap::with_capacity_and_hasher(capacity, hash_builder),
+            table: StringTable::with_capacity(capacity, None),
+            hashes: Vec::with_capacity(capacity),
+            __normalizer: PhantomData,
         }
     }
 
@@ -154,55 +234,13 @@ where
     #[inline]
     pub fn get_or_intern<T>(&mut self, val: T) -> K
     where
-        T: AsRef<V>,
+        T: AsInternable<V>,
     {
-        // When the feature-set supports it, only hash the value once
-        compile_expr! {
-            if #[any(feature = "nightly", feature = "hashbrown-table")] {
-                use core::hash::Hasher;
-
-                compile! {
-                    if #[feature = "hashbrown-table"] {
-                        use hashbrown::hash_map::RawEntryMut;
-                    } else if #[feature = "nightly"] {
-                        use std::collections::hash_map::RawEntryMut;
-                    }
-                }
-
-                let mut hasher = self.map.hasher().build_hasher();
-                val.as_ref().hash(&mut hasher);
-                let hash = hasher.finish();
-
-                match self.map.raw_entry_mut().from_key_hashed_nocheck(hash, val.as_ref()) {
-                    RawEntryMut::Occupied(entry) => *entry.get(),
-                    RawEntryMut::Vacant(entry) => {
-                        let key = K::try_from_usize(self.strings.len()).expect("Failed to get or intern string");
-
-                        // Safety: The drop impl removes all references before the arena is dropped
-                        let item = unsafe { V::from_raw(self.arena.store_slice(val.as_ref().to_raw())) };
-
-                        entry.insert_hashed_nocheck(hash, item, key);
-                        self.strings.push(item);
-
-                        key
-                    }
-                }
-            } else {
-                if let Some(key) = self.get(&val) {
-                    key
-                } else {
-                    let key = K::try_from_usize(self.strings.len()).expect("Failed to get or intern string");
-
-                    // Safety: The drop impl removes all references before the arena is dropped
-                    let item = unsafe { V::from_raw(self.arena.store_slice(val.as_ref().to_raw())) };
-
-                    self.map.insert(item, key);
-                    self.strings.push(item);
-
-                    key
-                }
-            }
-        }
+        let hash = self
+            .map
+            .hasher()
+            .hash_one(Normalized::<V, N>::new(val.as_internable()));
+        self.get_or_intern_with_hash(val, hash)
     }
 
     /// Get the key for a string, interning it if it does not yet exist
@@ -226,59 +264,111 @@ where
     #[inline]
     pub fn try_get_or_intern<T>(&mut self, val: T) -> Option<K>
     where
-        T: AsRef<V>,
+        T: AsInternable<V>,
     {
-        // When the feature-set supports it, only hash the value once
-        compile_expr! {
-            if #[any(feature = "nightly", feature = "hashbrown-table")] {
-                use core::hash::Hasher;
-
-                compile! {
-                    if #[feature = "hashbrown-table"] {
-                        use hashbrown::hash_map::RawEntryMut;
-                    } else if #[feature = "nightly"] {
-                        use std::collections::hash_map::RawEntryMut;
-                    }
-                }
-
-                let mut hasher = self.map.hasher().build_hasher();
-                val.as_ref().hash(&mut hasher);
-                let hash = hasher.finish();
-
-                match self.map.raw_entry_mut().from_key_hashed_nocheck(hash, val.as_ref()) {
-                    RawEntryMut::Occupied(entry) => Some(*entry.get()),
-                    RawEntryMut::Vacant(entry) => {
-                        let key = K::try_from_usize(self.strings.len())?;
-
-                        // Safety: The drop impl removes all references before the arena is dropped
-                        let item = unsafe { V::from_raw(self.arena.store_slice(val.as_ref().to_raw())) };
-
-                        entry.insert_hashed_nocheck(hash, item, key);
-                        self.strings.push(item);
-
-                        Some(key)
-                    }
-                }
-            } else {
-                if let Some(key) = self.get(&val) {
-                    Some(key)
-                } else {
-                    let key = K::try_from_usize(self.strings.len())?;
+        let hash = self
+            .map
+            .hasher()
+            .hash_one(Normalized::<V, N>::new(val.as_internable()));
+        self.try_get_or_intern_with_hash(val, hash)
+    }
 
-                    // Safety: The drop impl removes all references before the arena is dropped
-                    let item = unsafe { V::from_raw(self.arena.store_slice(val.as_ref().to_raw())) };
+    /// Get the key for a string using a hash that was already computed with [`hash_one`],
+    /// interning it if it does not yet exist
+    ///
+    /// The string is never rehashed, and the hash is cached for [`hash_of`]. Giving a hash that doesn't
+    /// match the string won't cause unsafety, but can cause duplicate keys and failed lookups
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key's `try_from_usize` function fails. With the default keys, this means that
+    /// you've interned more strings than it can handle. (For [`Spur`] this means that `u32::MAX - 1`
+    /// unique strings were interned)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    ///
+    /// let key = rodeo.get_or_intern_with_hash("Strings of things with wings and dings", hash);
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve(&key));
+    /// assert_eq!(hash, rodeo.hash_of(&key));
+    /// ```
+    ///
+    /// [`hash_one`]: Rodeo::hash_one
+    /// [`hash_of`]: Rodeo::hash_of
+    /// [`Spur`]: crate::Spur
+    #[inline]
+    pub fn get_or_intern_with_hash<T>(&mut self, val: T, hash: u64) -> K
+    where
+        T: AsInternable<V>,
+    {
+        self.try_get_or_intern_with_hash(val, hash)
+            .expect("Failed to get or intern string")
+    }
 
-                    self.map.insert(item, key);
-                    self.strings.push(item);
+    /// Get the key for a string using a hash that was already computed with [`hash_one`],
+    /// interning it if it does not yet exist
+    ///
+    /// Returns `None` if the key's `try_from_usize` function fails or the string can't be stored.
+    /// See [`get_or_intern_with_hash`] for how the hash is used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    ///
+    /// let key = rodeo.try_get_or_intern_with_hash("Strings of things with wings and dings", hash).unwrap();
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve(&key));
+    /// ```
+    ///
+    /// [`hash_one`]: Rodeo::hash_one
+    /// [`get_or_intern_with_hash`]: Rodeo::get_or_intern_with_hash
+    #[inline]
+    pub fn try_get_or_intern_with_hash<T>(&mut self, val: T, hash: u64) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        let val = val.as_internable();
+        let normalized = Normalized::<V, N>::new(val);
+        if let Some(key) = inline_key(normalized, self.table.is_terminated()) {
+            return Some(key);
+        }
+
+        let table = &self.table;
+        let entry = self.map.raw_entry_mut().from_hash(hash, |key| {
+            // Safety: Every key in the map was made for one of the strings
+            Normalized::new(unsafe { table.get_unchecked(key.into_usize()) }) == normalized
+        });
+
+        match entry {
+            RawEntryMut::Occupied(entry) => Some(*entry.key()),
+            RawEntryMut::Vacant(entry) => {
+                let key = K::try_from_usize(self.table.len())?;
+
+                let table = &mut self.table;
+                N::canonicalize(val, |canonical| table.push(canonical))?;
+                self.hashes.push(hash);
 
-                    Some(key)
-                }
+                let hashes = &self.hashes;
+                entry.insert_with_hasher(hash, key, (), |key| hash_of(hashes, key));
+
+                Some(key)
             }
         }
     }
 
     /// Get the key value of a string, returning `None` if it doesn't exist
     ///
+    /// With keys that hold short strings within themselves like [`InlineSpur`], a key is returned for
+    /// **every** short string, including ones that were never interned
+    ///
     /// # Example
     ///
     /// ```rust
@@ -292,12 +382,82 @@ where
     /// assert_eq!(None, rodeo.get("This string isn't interned"));
     /// ```
     ///
+    /// [`InlineSpur`]: crate::InlineSpur
     #[inline]
     pub fn get<T>(&self, val: T) -> Option<K>
     where
-        T: AsRef<V>,
+        T: AsInternable<V>,
+    {
+        let val = Normalized::<V, N>::new(val.as_internable());
+        self.find(val, self.map.hasher().hash_one(val))
+    }
+
+    /// Get the key value of a string using a hash that was already computed with [`hash_one`],
+    /// returning `None` if it doesn't exist
+    ///
+    /// The string is never rehashed. Giving a hash that doesn't match the string won't cause unsafety,
+    /// but may fail to find the string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    /// assert_eq!(Some(key), rodeo.get_with_hash("Strings of things with wings and dings", hash));
+    /// ```
+    ///
+    /// [`hash_one`]: Rodeo::hash_one
+    #[inline]
+    pub fn get_with_hash<T>(&self, val: T, hash: u64) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        self.find(Normalized::<V, N>::new(val.as_internable()), hash)
+    }
+
+    /// Finds the key of a string in the map by its hash
+    #[inline]
+    fn find(&self, val: &Normalized<V, N>, hash: u64) -> Option<K> {
+        if let Some(key) = inline_key(val, self.table.is_terminated()) {
+            return Some(key);
+        }
+
+        find_key(&self.map, hash, |idx| {
+            // Safety: Every key in the map was made for one of the strings
+            Normalized::new(unsafe { self.table.get_unchecked(idx) }) == val
+        })
+    }
+
+    /// Hashes a string with the Rodeo's hasher and [`Normalizer`], giving the hash
+    /// that [`get_or_intern_with_hash`] and [`get_with_hash`] expect
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let hash = rodeo.hash_one("Strings of things with wings and dings");
+    ///
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    /// assert_eq!(hash, rodeo.hash_of(&key));
+    /// ```
+    ///
+    /// [`Normalizer`]: crate::Normalizer
+    /// [`get_or_intern_with_hash`]: Rodeo::get_or_intern_with_hash
+    /// [`get_with_hash`]: Rodeo::get_with_hash
+    #[inline]
+    pub fn hash_one<T>(&self, val: T) -> u64
+    where
+        T: AsInternable<V>,
     {
-        self.map.get(val.as_ref()).copied()
+        self.map
+            .hasher()
+            .hash_one(Normalized::<V, N>::new(val.as_internable()))
     }
 
     /// Resolves a string by its key. Only keys made by the current Rodeo may be used
@@ -322,10 +482,10 @@ where
         // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
         // being symmetric and the caller having not fabricated a key. If the impl is sound
         // and symmetric, then it will succeed, as the usize used to create it is a valid
-        // index into self.strings
+        // index into self.table
         unsafe {
-            assert!(key.into_usize() < self.strings.len());
-            self.strings.get_unchecked(key.into_usize())
+            assert!(key.into_usize() < self.table.len());
+            self.table.get_unchecked(key.into_usize())
         }
     }
 
@@ -348,21 +508,15 @@ where
         // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
         // being symmetric and the caller having not fabricated a key. If the impl is sound
         // and symmetric, then it will succeed, as the usize used to create it is a valid
-        // index into self.strings
-        unsafe {
-            if key.into_usize() < self.strings.len() {
-                Some(self.strings.get_unchecked(key.into_usize()))
-            } else {
-                None
-            }
-        }
+        // index into self.table
+        unsafe { self.table.get(key.into_usize()) }
     }
 
     /// Resolves a string by its key, without bounds checks
     ///
     /// # Safety
     ///
-    /// The key must be valid for the current interner
+    /// The key must be valid for the current interner and can't hold its string within itself, see [`Key::is_inline`]
     ///
     /// # Example
     ///
@@ -379,7 +533,7 @@ where
     ///
     #[inline]
     pub unsafe fn resolve_unchecked<'a>(&'a self, key: &K) -> &'a V {
-        self.strings.get_unchecked(key.into_usize())
+        self.table.get_unchecked(key.into_usize())
     }
 
     /// Gets the number of interned strings
@@ -397,7 +551,7 @@ where
     ///
     #[inline]
     pub fn len(&self) -> usize {
-        self.strings.len()
+        self.table.len()
     }
 
     /// Returns `true` if there are no currently interned strings
@@ -429,7 +583,40 @@ where
     ///
     #[inline]
     pub fn capacity(&self) -> usize {
-        self.strings.capacity()
+        self.table.capacity()
+    }
+
+    /// Gets the hash of a string by its key, which is the same hash the Rodeo's map uses. Only keys
+    /// made by the current Rodeo may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    /// use std::{collections::hash_map::RandomState, hash::BuildHasher};
+    ///
+    /// let hasher = RandomState::new();
+    /// let mut rodeo: Rodeo<str, Spur, RandomState> = Rodeo::with_hasher(hasher.clone());
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// assert_eq!(
+    ///     hasher.hash_one("Strings of things with wings and dings"),
+    ///     rodeo.hash_of(&key),
+    /// );
+    /// ```
+    ///
+    #[inline]
+    pub fn hash_of(&self, key: &K) -> u64 {
+        if let Some(string) = key.as_inline() {
+            return self.map.hasher().hash_one(Normalized::<V, N>::new(string));
+        }
+
+        // Safety: The index is bounds checked, so a malformed key can only cause a panic
+        unsafe { self.hashes[key.into_usize()] }
     }
 
     // TODO: Examples here
@@ -447,11 +634,12 @@ where
     }
 }
 
-impl<V, K, S> Rodeo<V, K, S>
+impl<V, K, S, N> Rodeo<V, K, S, N>
 where
     V: Internable + ?Sized,
     K: Key + Default,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
     /// Consumes the current Rodeo, returning a [`RodeoReader`] to allow contention-free access of the interner
     /// from multiple threads
@@ -474,18 +662,47 @@ where
     /// [`RodeoReader`]: crate::RodeoReader
     #[inline]
     #[must_use]
-    pub fn into_reader(mut self) -> RodeoReader<V, K, S> {
-        let mut map = HashMap::with_capacity_and_hasher(self.map.len(), self.map.hasher().clone());
-        map.extend(self.map.drain());
+    pub fn into_reader(self) -> RodeoReader<V, K, S, N> {
+        // The map only holds keys and the table is already laid out the way the reader stores its strings,
+        // so the reader takes both as they are
+        RodeoReader::new(self.map, self.table, self.hashes)
+    }
 
-        // Safety: No other references outside of `map` and `strings` to the interned strings exist
-        unsafe {
-            RodeoReader::new(
-                map,
-                mem::take(&mut self.strings),
-                mem::take(&mut self.arena),
-            )
-        }
+    /// Consumes the current Rodeo, returning a [`RodeoReader`] with a bloom filter that rejects most lookups
+    /// of strings that aren't interned, see [`RodeoReader::build_bloom_filter`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` isn't between zero and one exclusive
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    ///
+    /// let read_only_rodeo = rodeo.into_reader_with_bloom_filter(0.01);
+    /// assert!(read_only_rodeo.has_bloom_filter());
+    /// assert_eq!(
+    ///     Some(key),
+    ///     read_only_rodeo.get("Appear weak when you are strong, and strong when you are weak."),
+    /// );
+    /// ```
+    ///
+    /// [`RodeoReader`]: crate::RodeoReader
+    /// [`RodeoReader::build_bloom_filter`]: crate::RodeoReader::build_bloom_filter
+    #[inline]
+    #[must_use]
+    pub fn into_reader_with_bloom_filter(
+        self,
+        false_positive_rate: f64,
+    ) -> RodeoReader<V, K, S, N> {
+        let mut reader = self.into_reader();
+        reader.build_bloom_filter(false_positive_rate);
+
+        reader
     }
 
     /// Consumes the current Rodeo, returning a [`RodeoResolver`] to allow contention-free access of the interner
@@ -509,11 +726,297 @@ where
     /// [`RodeoResolver`]: crate::RodeoResolver
     #[inline]
     #[must_use]
-    pub fn into_resolver(mut self) -> RodeoResolver<V, K> {
-        self.map.drain().for_each(drop);
+    pub fn into_resolver(self) -> RodeoResolver<V, K> {
+        // The map's allocation is dropped without visiting any of its entries
+        RodeoResolver::new(self.table)
+    }
+}
 
-        // Safety: No other references to the strings exist
-        unsafe { RodeoResolver::new(mem::take(&mut self.strings), mem::take(&mut self.arena)) }
+impl<V, K, S> Rodeo<V, K, S, Identity>
+where
+    V: Internable + Ord + ?Sized,
+    K: Key,
+    S: BuildHasher + Clone,
+{
+    /// Consumes the current Rodeo, returning a [`SortedReader`] that finds strings with a binary search
+    /// instead of a hash map along with the [`KeyRemap`] from the current keys to the reader's keys
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let b = rodeo.get_or_intern("b");
+    /// let a = rodeo.get_or_intern("a");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// assert_eq!("a", reader.resolve(&remap.remap(&a)));
+    /// assert_eq!("b", reader.resolve(&remap.remap(&b)));
+    ///
+    /// // Keys are ordered by their strings
+    /// assert!(reader.get("a").unwrap() < reader.get("b").unwrap());
+    /// ```
+    ///
+    /// [`SortedReader`]: crate::SortedReader
+    /// [`KeyRemap`]: crate::KeyRemap
+    #[inline]
+    #[must_use]
+    pub fn into_sorted_reader(mut self) -> (SortedReader<V, K>, KeyRemap<K>) {
+        // Drop the map's allocation without visiting any of its entries
+        self.map = KeyMap::with_hasher(self.map.hasher().clone());
+
+        SortedReader::sort(
+            &self.table.iter().collect::<Vec<_>>(),
+            self.table.terminator(),
+        )
+    }
+}
+
+impl<K, S> Rodeo<Utf16Str, K, S>
+where
+    K: Key,
+    S: BuildHasher + Clone,
+{
+    /// Get the key of a UTF-16 string from its UTF-8 equivalent, returning `None` if it doesn't exist
+    ///
+    /// The string is encoded into UTF-16 as it's hashed and compared, so nothing is allocated
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur, Utf16String};
+    ///
+    /// let mut rodeo: Rodeo<lasso::Utf16Str, Spur> = Rodeo::new();
+    /// let key = rodeo.get_or_intern(Utf16String::from("Strings of things with wings and dings"));
+    ///
+    /// assert_eq!(Some(key), rodeo.get_utf8("Strings of things with wings and dings"));
+    /// assert_eq!(None, rodeo.get_utf8("This string isn't interned"));
+    /// ```
+    ///
+    #[inline]
+    pub fn get_utf8(&self, string: &str) -> Option<K> {
+        find_key(
+            &self.map,
+            self.map.hasher().hash_one(EncodeUtf16(string)),
+            // Safety: Every key in the map was made for one of the strings
+            |idx| unsafe { self.table.get_unchecked(idx) } == string,
+        )
+    }
+}
+
+impl<K> Rodeo<str, K, RandomState>
+where
+    K: Key,
+{
+    /// Create a new Rodeo that stores a nul byte after every string, allowing any string to be
+    /// resolved as a `CStr` for free with [`resolve_cstr`]
+    ///
+    /// Strings containing interior nul bytes can't be interned by a nul terminated Rodeo, for them
+    /// [`try_get_or_intern`] will return `None` and [`get_or_intern`] will panic
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Hello, world!");
+    ///
+    /// assert_eq!(Some(key), rodeo.get("Hello, world!"));
+    /// assert_eq!(None, rodeo.try_get_or_intern("Hello,\0world!"));
+    /// # #[cfg(not(feature = "no-std"))]
+    /// assert_eq!(Ok("Hello, world!"), rodeo.resolve_cstr(&key).to_str());
+    /// ```
+    ///
+    /// [`resolve_cstr`]: Rodeo::resolve_cstr
+    /// [`try_get_or_intern`]: Rodeo::try_get_or_intern
+    /// [`get_or_intern`]: Rodeo::get_or_intern
+    #[inline]
+    pub fn nul_terminated() -> Self {
+        Self::nul_terminated_with_capacity_and_hasher(0, RandomState::new())
+    }
+}
+
+impl<K, S> Rodeo<str, K, S>
+where
+    K: Key,
+    S: BuildHasher + Clone,
+{
+    /// Creates a new nul terminated Rodeo with the specified capacity that will use the given hasher
+    /// for its internal hashmap, see [`nul_terminated`] for more details
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Spur, Rodeo};
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let rodeo: Rodeo<str, Spur, RandomState> =
+    ///     Rodeo::nul_terminated_with_capacity_and_hasher(10, RandomState::new());
+    /// assert!(rodeo.is_nul_terminated());
+    /// ```
+    ///
+    /// [`nul_terminated`]: Rodeo::nul_terminated
+    #[inline]
+    pub fn nul_terminated_with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            map: KeyMap::with_capacity_and_hasher(capacity, hash_builder),
+            table: StringTable::with_capacity(capacity, Some(Terminator::new(0))),
+            hashes: Vec::with_capacity(capacity),
+            __normalizer: PhantomData,
+        }
+    }
+}
+
+impl<K, S, N> Rodeo<str, K, S, N>
+where
+    K: Key,
+    S: BuildHasher + Clone,
+    N: Normalizer<str>,
+{
+    /// Resolves a string by its key into the given buffer, replacing its contents. Unlike [`resolve`], this
+    /// also resolves keys that hold their string within themselves, see [`InlineSpur`]. Only keys made by the
+    /// current Rodeo may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let mut buf = String::new();
+    /// assert_eq!("+", rodeo.resolve_into(&plus, &mut buf));
+    /// assert_eq!("Strings of things with wings and dings", rodeo.resolve_into(&long, &mut buf));
+    /// ```
+    ///
+    /// [`resolve`]: Rodeo::resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> &'b str {
+        self.try_resolve_into(key, buf).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents, or returns `None` and
+    /// leaves the buffer alone if the key is out of bounds. Unlike [`try_resolve`], this also resolves keys
+    /// that hold their string within themselves, see [`InlineSpur`]. Only keys made by the current Rodeo may
+    /// be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let mut buf = String::new();
+    /// assert_eq!(Some("+"), rodeo.try_resolve_into(&plus, &mut buf));
+    /// assert_eq!(None, rodeo.try_resolve_into(&InlineSpur::try_from_usize(10).unwrap(), &mut buf));
+    /// ```
+    ///
+    /// [`try_resolve`]: Rodeo::try_resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn try_resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> Option<&'b str> {
+        let string = match key.as_inline::<str>() {
+            Some(string) => string,
+            None => self.try_resolve(key)?,
+        };
+
+        buf.clear();
+        buf.push_str(string);
+
+        Some(buf.as_str())
+    }
+
+    /// Returns `true` if the Rodeo stores a nul byte after every string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// assert!(Rodeo::<str, Spur>::nul_terminated().is_nul_terminated());
+    /// assert!(!Rodeo::<str, Spur>::new().is_nul_terminated());
+    /// ```
+    ///
+    #[inline]
+    pub fn is_nul_terminated(&self) -> bool {
+        self.table.is_terminated()
+    }
+
+    /// Resolves a string by its key as a `CStr`, which costs nothing more than a normal resolution.
+    /// Only keys made by the current Rodeo may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds or if the Rodeo isn't [`nul_terminated`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// assert_eq!(
+    ///     b"Strings of things with wings and dings\0",
+    ///     rodeo.resolve_cstr(&key).to_bytes_with_nul(),
+    /// );
+    /// ```
+    ///
+    /// [`nul_terminated`]: Rodeo::nul_terminated
+    #[cfg(not(feature = "no-std"))]
+    #[inline]
+    pub fn resolve_cstr<'a>(&'a self, key: &K) -> &'a std::ffi::CStr {
+        assert!(self.is_nul_terminated(), "The Rodeo is not nul terminated");
+
+        // Safety: The key is bounds checked and the table is nul terminated
+        unsafe {
+            assert!(key.into_usize() < self.table.len());
+            self.table.get_cstr_unchecked(key.into_usize())
+        }
+    }
+
+    /// Resolves a string by its key as a `CStr`, returning `None` if it's out of bounds or if the Rodeo
+    /// isn't [`nul_terminated`]. Only keys made by the current Rodeo may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Rodeo, Spur};
+    ///
+    /// let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// assert_eq!(
+    ///     Some("Strings of things with wings and dings"),
+    ///     rodeo.try_resolve_cstr(&key).and_then(|cstr| cstr.to_str().ok()),
+    /// );
+    /// ```
+    ///
+    /// [`nul_terminated`]: Rodeo::nul_terminated
+    #[cfg(not(feature = "no-std"))]
+    #[inline]
+    pub fn try_resolve_cstr<'a>(&'a self, key: &K) -> Option<&'a std::ffi::CStr> {
+        // Safety: The key is bounds checked and the table is nul terminated
+        unsafe {
+            if self.is_nul_terminated() && key.into_usize() < self.table.len() {
+                Some(self.table.get_cstr_unchecked(key.into_usize()))
+            } else {
+                None
+            }
+        }
     }
 }
 
@@ -528,39 +1031,47 @@ impl Default for Rodeo<str, Spur, RandomState> {
     }
 }
 
-/// Deallocate the leaked strings interned by Rodeo
-impl<V, K, S> Drop for Rodeo<V, K, S>
+/// Copies the table of strings, cloning the map since it only holds keys
+impl<V, K, S, N> Clone for Rodeo<V, K, S, N>
 where
     V: Internable + ?Sized,
     K: Key,
     S: BuildHasher + Clone,
+    N: Normalizer<V>,
 {
     #[inline]
-    fn drop(&mut self) {
-        // Clear the map to remove all other references to the strings in self.strings
-        self.map.clear();
-
-        // Safety: There must not be any other references to the strings in the arena, so
-        // all strings are drained before the arena can drop
-        self.strings.drain(..).for_each(drop);
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+            table: self.table.clone(),
+            hashes: self.hashes.clone(),
+            __normalizer: PhantomData,
+        }
     }
 }
 
-unsafe impl<V, K, S> Send for Rodeo<V, K, S>
+unsafe impl<V, K, S, N> Send for Rodeo<V, K, S, N>
 where
     V: Internable + ?Sized + Send,
     K: Key + Send,
     S: BuildHasher + Clone + Send,
+    N: Normalizer<V>,
 {
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{hasher::RandomState, Key, MicroSpur, Rodeo, Spur};
+    use crate::{
+        hasher::RandomState, AsciiCaseInsensitive, InlineSpur, Key, MicroSpur, Rodeo, Spur,
+        Utf16Str, Utf16String,
+    };
 
     compile! {
         if #[feature = "no-std"] {
-            use alloc::string::ToString;
+            use alloc::{
+                string::{String, ToString},
+                vec::Vec,
+            };
         }
     }
 
@@ -655,7 +1166,7 @@ mod tests {
     fn try_get_or_intern() {
         let mut rodeo: Rodeo<str, MicroSpur> = Rodeo::new();
 
-        for i in 0..u8::max_value() as usize - 1 {
+        for i in 0..u8::MAX as usize - 1 {
             rodeo.get_or_intern(i.to_string());
         }
 
@@ -726,20 +1237,219 @@ mod tests {
         assert!(rodeo.is_empty());
     }
 
-    // #[test]
-    // fn clone_rodeo() {
-    //     let mut rodeo = Rodeo::default();
-    //     let key = rodeo.get_or_intern("Test");
-    //
-    //     assert_eq!("Test", rodeo.resolve(&key));
-    //
-    //     let cloned = rodeo.clone();
-    //     assert_eq!("Test", cloned.resolve(&key));
-    //
-    //     drop(rodeo);
-    //
-    //     assert_eq!("Test", cloned.resolve(&key));
-    // }
+    #[test]
+    fn clone_rodeo() {
+        let mut rodeo = Rodeo::default();
+        let key = rodeo.get_or_intern("Test");
+
+        assert_eq!("Test", rodeo.resolve(&key));
+
+        let cloned = rodeo.clone();
+        assert_eq!("Test", cloned.resolve(&key));
+
+        drop(rodeo);
+
+        assert_eq!("Test", cloned.resolve(&key));
+    }
+
+    #[test]
+    fn clone_keeps_lookups() {
+        let mut rodeo: Rodeo<str, Spur, RandomState, AsciiCaseInsensitive> =
+            Rodeo::with_normalizer();
+        let a = rodeo.get_or_intern("Hello");
+        let b = rodeo.get_or_intern("World");
+
+        let mut cloned = rodeo.clone();
+        drop(rodeo);
+
+        assert_eq!(Some(a), cloned.get("HELLO"));
+        assert_eq!(Some(b), cloned.get("world"));
+        assert_eq!(a, cloned.get_or_intern("hello"));
+
+        let c = cloned.get_or_intern("Foo");
+        assert_eq!(Spur::try_from_usize(2).unwrap(), c);
+        assert_eq!("Foo", cloned.resolve(&c));
+    }
+
+    #[test]
+    fn hash_of() {
+        let mut rodeo = Rodeo::default();
+        let a = rodeo.get_or_intern("A");
+        let b = rodeo.get_or_intern("B");
+
+        assert_ne!(rodeo.hash_of(&a), rodeo.hash_of(&b));
+
+        let (hash_a, hash_b) = (rodeo.hash_of(&a), rodeo.hash_of(&b));
+        let cloned = rodeo.clone();
+        assert_eq!(hash_a, cloned.hash_of(&a));
+
+        let reader = rodeo.into_reader();
+        assert_eq!(hash_a, reader.hash_of(&a));
+        assert_eq!(hash_b, reader.hash_of(&b));
+        assert_eq!(Some(a), reader.get("A"));
+        assert_eq!(Some(b), reader.get("B"));
+    }
+
+    #[test]
+    fn with_hash() {
+        let mut rodeo = Rodeo::default();
+        let hash = rodeo.hash_one("A");
+
+        assert_eq!(None, rodeo.get_with_hash("A", hash));
+
+        let a = rodeo.get_or_intern_with_hash("A", hash);
+        assert_eq!(a, rodeo.get_or_intern("A"));
+        assert_eq!(a, rodeo.get_or_intern_with_hash("A", hash));
+        assert_eq!(Some(a), rodeo.try_get_or_intern_with_hash("A", hash));
+        assert_eq!(Some(a), rodeo.get_with_hash("A", hash));
+        assert_eq!(hash, rodeo.hash_of(&a));
+
+        let b = rodeo.get_or_intern("B");
+        assert_eq!(Some(b), rodeo.get_with_hash("B", rodeo.hash_one("B")));
+
+        let reader = rodeo.into_reader();
+        assert_eq!(hash, reader.hash_one("A"));
+        assert_eq!(Some(a), reader.get_with_hash("A", hash));
+        assert_eq!(None, reader.get_with_hash("C", reader.hash_one("C")));
+    }
+
+    #[test]
+    fn with_hash_normalized() {
+        let mut rodeo: Rodeo<str, Spur, RandomState, AsciiCaseInsensitive> =
+            Rodeo::with_normalizer();
+        let hash = rodeo.hash_one("Hello");
+        assert_eq!(hash, rodeo.hash_one("hELLO"));
+
+        let key = rodeo.get_or_intern_with_hash("Hello", hash);
+        assert_eq!(key, rodeo.get_or_intern_with_hash("HELLO", hash));
+        assert_eq!(Some(key), rodeo.get_with_hash("hello", hash));
+    }
+
+    #[test]
+    fn colliding_hashes() {
+        let mut rodeo = Rodeo::default();
+        let a = rodeo.get_or_intern_with_hash("A", 0);
+        let b = rodeo.get_or_intern_with_hash("B", 0);
+
+        assert_ne!(a, b);
+        assert_eq!(Some(a), rodeo.get_with_hash("A", 0));
+        assert_eq!(Some(b), rodeo.get_with_hash("B", 0));
+        assert_eq!(None, rodeo.get_with_hash("C", 0));
+
+        let reader = rodeo.into_reader();
+        assert_eq!(Some(b), reader.get_with_hash("B", 0));
+    }
+
+    #[test]
+    fn inline_keys() {
+        let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+        let empty = rodeo.get_or_intern("");
+        let plus = rodeo.get_or_intern("+");
+        let arrow = rodeo.try_get_or_intern("=>").unwrap();
+        let long = rodeo.get_or_intern("Hello");
+
+        assert!(empty.is_inline() && plus.is_inline() && arrow.is_inline());
+        assert!(!long.is_inline());
+        assert_eq!(1, rodeo.len());
+
+        assert_eq!(plus, rodeo.get_or_intern("+"));
+        assert_eq!(long, rodeo.get_or_intern("Hello"));
+        assert_eq!(Some(arrow), rodeo.get("=>"));
+        assert_eq!(Some(plus), rodeo.get("+"));
+        assert_eq!(None, rodeo.get("World"));
+        assert_eq!(rodeo.hash_one("+"), rodeo.hash_of(&plus));
+
+        // Strings held within keys can't be borrowed from the interner, only copied out of the key
+        let mut buf = String::new();
+        assert_eq!(None, rodeo.try_resolve(&plus));
+        assert_eq!("+", rodeo.resolve_into(&plus, &mut buf));
+        assert_eq!(Some("=>"), rodeo.try_resolve_into(&arrow, &mut buf));
+        assert_eq!("Hello", rodeo.resolve_into(&long, &mut buf));
+
+        let reader = rodeo.clone().into_reader_with_bloom_filter(0.01);
+        assert_eq!("", reader.resolve_into(&empty, &mut buf));
+        assert_eq!(Some(plus), reader.get("+"));
+        assert_eq!(Some(plus), reader.get_with_hash("+", 0));
+        assert_eq!(Some(long), reader.get("Hello"));
+
+        let (sorted, remap) = rodeo.clone().into_sorted_reader();
+        assert_eq!(Some(plus), sorted.get("+"));
+        assert_eq!(plus, remap.remap(&plus));
+        assert_eq!("Hello", sorted.resolve(&remap.remap(&long)));
+        assert_eq!("+", sorted.resolve_into(&remap.remap(&plus), &mut buf));
+
+        let resolver = rodeo.into_resolver();
+        assert_eq!("=>", resolver.resolve_into(&arrow, &mut buf));
+        assert_eq!(Some("Hello"), resolver.try_resolve(&long));
+
+        let front_coded = resolver.into_front_coded(4);
+        assert_eq!("=>", front_coded.resolve(&arrow));
+        assert_eq!("Hello", front_coded.resolve_into(&long, &mut buf));
+    }
+
+    #[test]
+    fn inline_keys_need_exact_unterminated_strings() {
+        let mut rodeo: Rodeo<str, InlineSpur, RandomState, AsciiCaseInsensitive> =
+            Rodeo::with_normalizer();
+        let a = rodeo.get_or_intern("a");
+        assert!(!a.is_inline());
+        assert_eq!(Some(a), rodeo.get("A"));
+
+        let mut rodeo: Rodeo<str, InlineSpur, RandomState> =
+            Rodeo::nul_terminated_with_capacity_and_hasher(0, RandomState::new());
+        let plus = rodeo.get_or_intern("+");
+        assert!(!plus.is_inline());
+        assert_eq!("+", rodeo.resolve(&plus));
+        assert_eq!(1, rodeo.len());
+    }
+
+    #[test]
+    fn get_utf8() {
+        let strings = ["", "a", "Hello, 𝄞 world!", "\u{FFFF}"];
+        let mut rodeo: Rodeo<Utf16Str, Spur> = Rodeo::new();
+        let keys = strings
+            .iter()
+            .map(|&string| rodeo.get_or_intern(Utf16String::from(string)))
+            .collect::<Vec<_>>();
+
+        for (&string, &key) in strings.iter().zip(&keys) {
+            assert_eq!(Some(key), rodeo.get_utf8(string));
+        }
+        assert_eq!(None, rodeo.get_utf8("Hello, world!"));
+
+        let mut reader = rodeo.into_reader();
+        reader.build_bloom_filter(0.01);
+        for (&string, &key) in strings.iter().zip(&keys) {
+            assert_eq!(Some(key), reader.get_utf8(string));
+        }
+        assert_eq!(None, reader.get_utf8("Hello, world!"));
+    }
+
+    #[test]
+    fn lookups_after_growing() {
+        let mut rodeo = Rodeo::default();
+        let keys = (0..1000)
+            .map(|i| rodeo.get_or_intern(i.to_string()))
+            .collect::<Vec<_>>();
+
+        for (i, &key) in keys.iter().enumerate() {
+            assert_eq!(Some(key), rodeo.get(i.to_string()));
+        }
+
+        let reader = rodeo.clone().into_reader();
+        for (i, &key) in keys.iter().enumerate() {
+            assert_eq!(Some(key), reader.get(i.to_string()));
+        }
+        assert_eq!(None, reader.get("1000"));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn hash_of_panics() {
+        let rodeo = Rodeo::default();
+        rodeo.hash_of(&Spur::try_from_usize(100).unwrap());
+    }
 
     #[test]
     fn drop_rodeo() {
@@ -774,6 +1484,76 @@ mod tests {
         assert_eq!(None, rodeo.next());
     }
 
+    #[test]
+    fn nul_terminated() {
+        let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+        assert!(rodeo.is_nul_terminated());
+        assert!(!Rodeo::default().is_nul_terminated());
+
+        let a = rodeo.get_or_intern("A");
+        let empty = rodeo.get_or_intern("");
+        assert_eq!(a, rodeo.get_or_intern("A"));
+        assert_eq!(Some(a), rodeo.get("A"));
+        assert_eq!("A", rodeo.resolve(&a));
+        assert_eq!("", rodeo.resolve(&empty));
+
+        // Strings with interior nuls are rejected
+        assert_eq!(None, rodeo.try_get_or_intern("A\0B"));
+        assert_eq!(None, rodeo.try_get_or_intern("\0"));
+        assert_eq!(None, rodeo.get("A\0B"));
+        assert_eq!(2, rodeo.len());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn nul_terminated_interior_nul_panics() {
+        let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+        rodeo.get_or_intern("A\0B");
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn resolve_cstr() {
+        let mut rodeo: Rodeo<str, Spur> = Rodeo::nul_terminated();
+        let a = rodeo.get_or_intern("A");
+        let empty = rodeo.get_or_intern("");
+
+        assert_eq!(b"A\0", rodeo.resolve_cstr(&a).to_bytes_with_nul());
+        assert_eq!(b"\0", rodeo.resolve_cstr(&empty).to_bytes_with_nul());
+        assert_eq!(
+            Some(&b"A\0"[..]),
+            rodeo
+                .try_resolve_cstr(&a)
+                .map(|cstr| cstr.to_bytes_with_nul()),
+        );
+        assert_eq!(
+            None,
+            rodeo.try_resolve_cstr(&Spur::try_from_usize(10).unwrap())
+        );
+
+        // Fill past the end of the first bucket
+        let keys: Vec<_> = (0..2000)
+            .map(|i| rodeo.get_or_intern(i.to_string()))
+            .collect();
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(Ok(&*i.to_string()), rodeo.resolve_cstr(key).to_str());
+        }
+
+        let mut rodeo = Rodeo::default();
+        let a = rodeo.get_or_intern("A");
+        assert_eq!(None, rodeo.try_resolve_cstr(&a));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(any(miri, feature = "no-std")))]
+    fn resolve_cstr_not_terminated_panics() {
+        let mut rodeo = Rodeo::default();
+        let a = rodeo.get_or_intern("A");
+        rodeo.resolve_cstr(&a);
+    }
+
     #[test]
     #[cfg(not(any(feature = "no-std", feature = "ahasher")))]
     fn debug() {
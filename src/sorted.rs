@@ -0,0 +1,593 @@
+use crate::{
+    front_coded::FrontCodedResolver,
+    internable::{AsInternable, Internable},
+    key::{Key, Spur},
+    remap::KeyRemap,
+    resolver::RodeoResolver,
+    table::{StringTable, Terminator},
+    util::{sorted_order, Iter, Strings},
+};
+
+use core::{cmp::Ordering, marker::PhantomData, num::NonZeroUsize};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::{string::String, vec::Vec};
+    }
+}
+
+/// A read-only interner that holds its strings in sorted order and finds them with a binary search,
+/// trading the speed of a [`RodeoReader`]'s lookups for not having a hash map at all
+///
+/// Keys are assigned in the order of their strings, so comparing two keys gives the same result as comparing
+/// their strings. Since this means the keys of the interner it was created from can't be kept, converting
+/// into a `SortedReader` with [`Rodeo::into_sorted_reader`] or [`RodeoReader::into_sorted_reader`] also
+/// gives a [`KeyRemap`] from the old keys to the new ones
+///
+/// Strings are compared exactly, so only interners using the [`Identity`] normalizer can be converted
+///
+/// [`RodeoReader`]: crate::RodeoReader
+/// [`Rodeo::into_sorted_reader`]: crate::Rodeo::into_sorted_reader
+/// [`RodeoReader::into_sorted_reader`]: crate::RodeoReader::into_sorted_reader
+/// [`Identity`]: crate::Identity
+#[derive(Debug)]
+pub struct SortedReader<V = str, K = Spur>
+where
+    V: Internable + ?Sized,
+    K: Key,
+{
+    /// The interned strings in sorted order, where each string's index is its key
+    pub(crate) table: StringTable<V>,
+    __key: PhantomData<K>,
+}
+
+impl<V, K> SortedReader<V, K>
+where
+    V: Internable + Ord + ?Sized,
+    K: Key,
+{
+    /// Sorts the strings of another interner into a new table, giving every distinct string a key in sorted
+    /// order and returning the new key of every old key
+    ///
+    /// The strings can't contain the terminator
+    ///
+    #[inline]
+    pub(crate) fn sort(
+        strings: &[&V],
+        terminator: Option<Terminator<V::Raw>>,
+    ) -> (Self, KeyRemap<K>) {
+        let order = sorted_order(strings.len(), |idx| strings[idx]);
+
+        let mut sorted: Vec<&V> = Vec::with_capacity(strings.len());
+        let mut keys = Vec::with_capacity(strings.len());
+        keys.resize(
+            strings.len(),
+            K::try_from_usize(0).unwrap_or_else(|| unreachable!()),
+        );
+
+        for old in order {
+            let string = strings[old];
+
+            // Equal strings are next to each other once sorted, and any duplicates share the key of the first.
+            // Duplicates can only come from interners that don't check for them, and they aren't stored again
+            if !matches!(sorted.last(), Some(&last) if last == string) {
+                sorted.push(string);
+            }
+
+            // There can't be more distinct strings than there were keys, so the new key can always be made
+            keys[old] = K::try_from_usize(sorted.len() - 1).unwrap_or_else(|| unreachable!());
+        }
+
+        let reader = Self {
+            table: StringTable::new(&sorted, terminator),
+            __key: PhantomData,
+        };
+
+        (reader, KeyRemap::new(keys))
+    }
+
+    /// Get the key value of a string, returning `None` if it doesn't exist
+    ///
+    /// With keys that hold short strings within themselves like [`InlineSpur`], a key is returned for
+    /// **every** short string, including ones that were never interned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, _) = rodeo.into_sorted_reader();
+    /// let key = reader.get("Strings of things with wings and dings").unwrap();
+    /// assert_eq!("Strings of things with wings and dings", reader.resolve(&key));
+    ///
+    /// assert_eq!(None, reader.get("This string isn't interned"));
+    /// ```
+    ///
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn get<T>(&self, val: T) -> Option<K>
+    where
+        T: AsInternable<V>,
+    {
+        let val = val.as_internable();
+        if let Some(key) = K::try_inline(val) {
+            return Some(key);
+        }
+
+        let (mut low, mut high) = (0, self.table.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+
+            // Safety: `mid` is always between `low` and `high`, which never exceed the number of strings
+            match unsafe { self.table.get_unchecked(mid) }.cmp(val) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => {
+                    return Some(K::try_from_usize(mid).unwrap_or_else(|| unreachable!()))
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Checks if the given string exists
+    ///
+    /// With keys that hold short strings within themselves like [`InlineSpur`], this is `true` for
+    /// **every** short string, including ones that were never interned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, _) = rodeo.into_sorted_reader();
+    /// assert!(reader.contains("Strings of things with wings and dings"));
+    /// assert!(!reader.contains("This string isn't interned"));
+    /// ```
+    ///
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn contains<T>(&self, val: T) -> bool
+    where
+        T: AsInternable<V>,
+    {
+        self.get(val).is_some()
+    }
+}
+
+impl<V, K> SortedReader<V, K>
+where
+    V: Internable + ?Sized,
+    K: Key,
+{
+    /// Checks if a key exists in the current reader
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{Key, Rodeo, Spur};
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// assert!(reader.contains_key(&remap.remap(&key)));
+    /// assert!(!reader.contains_key(&Spur::try_from_usize(10).unwrap()));
+    /// ```
+    ///
+    #[inline]
+    pub fn contains_key(&self, key: &K) -> bool {
+        // Safety: The index is only compared against the length, so a fabricated key can only fail to be found
+        unsafe { key.into_usize() < self.table.len() }
+    }
+
+    /// Resolves a string by its key. Only keys made by the current reader or remapped to it may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// assert_eq!("Strings of things with wings and dings", reader.resolve(&remap.remap(&key)));
+    /// ```
+    ///
+    #[inline]
+    pub fn resolve<'a>(&'a self, key: &K) -> &'a V {
+        // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
+        // being symmetric and the caller having not fabricated a key. If the impl is sound
+        // and symmetric, then it will succeed, as the usize used to create it is a valid
+        // index into self.table
+        unsafe {
+            assert!(key.into_usize() < self.table.len());
+
+            self.table.get_unchecked(key.into_usize())
+        }
+    }
+
+    /// Resolves a string by its key, returning `None` if the key is out of bounds. Only keys
+    /// made by the current reader or remapped to it may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// assert_eq!(
+    ///     Some("Strings of things with wings and dings"),
+    ///     reader.try_resolve(&remap.remap(&key)),
+    /// );
+    /// ```
+    ///
+    #[inline]
+    pub fn try_resolve<'a>(&'a self, key: &K) -> Option<&'a V> {
+        // Safety: The call to get_unchecked's safety relies on the Key::into_usize impl
+        // being symmetric and the caller having not fabricated a key. If the impl is sound
+        // and symmetric, then it will succeed, as the usize used to create it is a valid
+        // index into self.table
+        unsafe { self.table.get(key.into_usize()) }
+    }
+
+    /// Resolves a string by its key without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// The key must be valid for the current reader and can't hold its string within itself, see [`Key::is_inline`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// unsafe {
+    ///     assert_eq!(
+    ///         "Strings of things with wings and dings",
+    ///         reader.resolve_unchecked(&remap.remap(&key)),
+    ///     );
+    /// }
+    /// ```
+    ///
+    #[inline]
+    pub unsafe fn resolve_unchecked<'a>(&'a self, key: &K) -> &'a V {
+        self.table.get_unchecked(key.into_usize())
+    }
+
+    /// Gets the number of interned strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// rodeo.get_or_intern("Documentation often has little hidden bits in it");
+    ///
+    /// let (reader, _) = rodeo.into_sorted_reader();
+    /// assert_eq!(reader.len(), 1);
+    /// ```
+    ///
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns `true` if there are no currently interned strings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let rodeo = Rodeo::default();
+    ///
+    /// let (reader, _) = rodeo.into_sorted_reader();
+    /// assert!(reader.is_empty());
+    /// ```
+    ///
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the interned strings and their key values in sorted order
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, V, K> {
+        Iter::from_sorted(self)
+    }
+
+    /// Returns an iterator over the interned strings in sorted order
+    #[inline]
+    pub fn strings(&self) -> Strings<'_, V, K> {
+        Strings::from_sorted(self)
+    }
+
+    /// Consumes the current reader, making it into a [`RodeoResolver`] that keeps its keys
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("Appear weak when you are strong, and strong when you are weak.");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// let resolver = reader.into_resolver();
+    /// assert_eq!(
+    ///     "Appear weak when you are strong, and strong when you are weak.",
+    ///     resolver.resolve(&remap.remap(&key)),
+    /// );
+    /// ```
+    ///
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    #[inline]
+    #[must_use]
+    pub fn into_resolver(self) -> RodeoResolver<V, K> {
+        RodeoResolver::new(self.table)
+    }
+}
+
+impl<K> SortedReader<str, K>
+where
+    K: Key,
+{
+    /// Resolves a string by its key into the given buffer, replacing its contents. Unlike [`resolve`], this
+    /// also resolves keys that hold their string within themselves, see [`InlineSpur`]. Only keys made by the
+    /// current reader or remapped to it may be used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is out of bounds
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// let mut buf = String::new();
+    /// assert_eq!("+", reader.resolve_into(&remap.remap(&plus), &mut buf));
+    /// assert_eq!(
+    ///     "Strings of things with wings and dings",
+    ///     reader.resolve_into(&remap.remap(&long), &mut buf),
+    /// );
+    /// ```
+    ///
+    /// [`resolve`]: SortedReader::resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> &'b str {
+        self.try_resolve_into(key, buf).expect("Key out of bounds")
+    }
+
+    /// Resolves a string by its key into the given buffer, replacing its contents, or returns `None` and
+    /// leaves the buffer alone if the key is out of bounds. Unlike [`try_resolve`], this also resolves keys
+    /// that hold their string within themselves, see [`InlineSpur`]. Only keys made by the current reader or
+    /// remapped to it may be used
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::{InlineSpur, Key, Rodeo};
+    ///
+    /// let mut rodeo: Rodeo<str, InlineSpur> = Rodeo::new();
+    /// let plus = rodeo.get_or_intern("+");
+    /// let long = rodeo.get_or_intern("Strings of things with wings and dings");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// let mut buf = String::new();
+    /// assert_eq!(Some("+"), reader.try_resolve_into(&remap.remap(&plus), &mut buf));
+    /// assert_eq!(None, reader.try_resolve_into(&InlineSpur::try_from_usize(10).unwrap(), &mut buf));
+    /// ```
+    ///
+    /// [`try_resolve`]: SortedReader::try_resolve
+    /// [`InlineSpur`]: crate::InlineSpur
+    #[inline]
+    pub fn try_resolve_into<'b>(&self, key: &K, buf: &'b mut String) -> Option<&'b str> {
+        let string = match key.as_inline::<str>() {
+            Some(string) => string,
+            None => self.try_resolve(key)?,
+        };
+
+        buf.clear();
+        buf.push_str(string);
+
+        Some(buf.as_str())
+    }
+
+    /// Consumes the current reader, coding its strings into a [`FrontCodedResolver`] that stores
+    /// `block_size` strings per block and keeps the same keys. Since neighbouring keys of a sorted
+    /// reader share the longest prefixes possible, this compresses better than coding a [`RodeoResolver`]
+    ///
+    /// See [`FrontCodedResolver`] for how the block size trades memory for speed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Rodeo;
+    ///
+    /// let mut rodeo = Rodeo::default();
+    /// let key = rodeo.get_or_intern("crate::module::Type::method");
+    ///
+    /// let (reader, remap) = rodeo.into_sorted_reader();
+    /// let resolver = reader.into_front_coded(16);
+    /// assert_eq!("crate::module::Type::method", resolver.resolve(&remap.remap(&key)));
+    /// ```
+    ///
+    /// [`FrontCodedResolver`]: crate::FrontCodedResolver
+    /// [`RodeoResolver`]: crate::RodeoResolver
+    #[inline]
+    #[must_use]
+    pub fn into_front_coded(self, block_size: usize) -> FrontCodedResolver<K> {
+        let block_size = NonZeroUsize::new(block_size).expect("The block size must be at least 1");
+
+        FrontCodedResolver::new(&self.table, block_size)
+    }
+}
+
+/// Copies every string into a new buffer
+impl<V, K> Clone for SortedReader<V, K>
+where
+    V: Internable + ?Sized,
+    K: Key,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            table: self.table.clone(),
+            __key: PhantomData,
+        }
+    }
+}
+
+unsafe impl<V, K> Send for SortedReader<V, K>
+where
+    V: Internable + ?Sized + Send,
+    K: Key + Send,
+{
+}
+
+unsafe impl<V, K> Sync for SortedReader<V, K>
+where
+    V: Internable + ?Sized + Sync,
+    K: Key + Sync,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Key, Rodeo, Spur};
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::vec::Vec;
+        }
+    }
+
+    #[test]
+    fn sorted_keys() {
+        let strings = ["parse", "b", "parent", "", "pa", "ü", "q"];
+
+        let mut rodeo = Rodeo::default();
+        let keys = strings
+            .iter()
+            .map(|string| rodeo.get_or_intern(string))
+            .collect::<Vec<Spur>>();
+
+        let (reader, remap) = rodeo.into_sorted_reader();
+        assert_eq!(strings.len(), reader.len());
+        assert_eq!(strings.len(), remap.len());
+
+        let mut sorted = strings.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, reader.strings().collect::<Vec<_>>());
+
+        for (key, string) in keys.iter().zip(strings.iter()) {
+            let new = remap.remap(key);
+
+            assert_eq!(*string, reader.resolve(&new));
+            assert_eq!(Some(new), reader.get(string));
+            assert!(reader.contains(string));
+        }
+
+        let iter = reader.iter().collect::<Vec<_>>();
+        assert!(iter.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(iter.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
+
+    #[test]
+    fn misses() {
+        let mut rodeo = Rodeo::default();
+        rodeo.get_or_intern("b");
+        rodeo.get_or_intern("d");
+
+        let (reader, remap) = rodeo.into_sorted_reader();
+        for miss in &["", "a", "c", "e", "bb"] {
+            assert_eq!(None, reader.get(miss));
+            assert!(!reader.contains(miss));
+        }
+
+        let out_of_bounds = Spur::try_from_usize(2).unwrap();
+        assert!(!reader.contains_key(&out_of_bounds));
+        assert_eq!(None, reader.try_resolve(&out_of_bounds));
+        assert_eq!(None, remap.get(&out_of_bounds));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(miri))]
+    fn resolve_out_of_bounds() {
+        let (reader, _) = Rodeo::default().into_sorted_reader();
+        reader.resolve(&Spur::try_from_usize(10).unwrap());
+    }
+
+    #[test]
+    fn empty() {
+        let (reader, remap) = Rodeo::default().into_sorted_reader();
+
+        assert!(reader.is_empty());
+        assert!(remap.is_empty());
+        assert_eq!(None, reader.get("a"));
+    }
+
+    #[test]
+    fn slices() {
+        let mut rodeo: Rodeo<[u8], Spur> = Rodeo::new();
+        let b = rodeo.get_or_intern(&[2, 1][..]);
+        let a = rodeo.get_or_intern(&[1, 2, 3][..]);
+
+        let (reader, remap) = rodeo.into_reader().into_sorted_reader();
+        assert_eq!(Spur::try_from_usize(0).unwrap(), remap.remap(&a));
+        assert_eq!(Spur::try_from_usize(1).unwrap(), remap.remap(&b));
+        assert_eq!(Some(remap.remap(&b)), reader.get(&[2, 1][..]));
+    }
+
+    #[test]
+    fn clone_and_resolver() {
+        let mut rodeo = Rodeo::default();
+        let keys = ["c", "a", "b"]
+            .iter()
+            .map(|string| rodeo.get_or_intern(string))
+            .collect::<Vec<_>>();
+
+        let (reader, remap) = rodeo.into_sorted_reader();
+        let cloned = reader.clone();
+        drop(reader);
+
+        let resolver = cloned.clone().into_resolver();
+        for (key, string) in keys.iter().zip(["c", "a", "b"].iter()) {
+            assert_eq!(*string, cloned.resolve(&remap.remap(key)));
+            assert_eq!(*string, resolver.resolve(&remap.remap(key)));
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{key::Key, table::StringTable};
+
+use core::{convert::TryFrom, mem, num::NonZeroUsize};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::vec::Vec;
+    }
+}
+
+/// A sparse suffix array over interned strings, holding the suffix starting at every `stride`th
+/// character of every string sorted by its contents
+///
+/// Any occurrence of a needle at least `stride` characters long covers one of the indexed positions,
+/// so it can be found by searching for each of the needle's first `stride` suffixes
+#[derive(Debug, Clone)]
+pub(crate) struct SubstringIndex<K: Key> {
+    /// The key of each suffix's string along with the byte offset the suffix starts at
+    suffixes: Vec<(K, u32)>,
+    stride: NonZeroUsize,
+}
+
+impl<K: Key> SubstringIndex<K> {
+    /// Creates the index for the given strings, where each string's index is its key
+    ///
+    /// # Panics
+    ///
+    /// Panics if a string is longer than `u32::MAX` bytes
+    ///
+    #[inline]
+    pub(crate) fn new(strings: &StringTable<str>, stride: NonZeroUsize) -> Self {
+        assert!(
+            !K::can_inline::<str>(),
+            "Indices can't be built over keys that hold strings within themselves"
+        );
+
+        let mut suffixes = Vec::new();
+        for (idx, string) in strings.iter().enumerate() {
+            let key = K::try_from_usize(idx).unwrap_or_else(|| unreachable!());
+
+            suffixes.extend(
+                string
+                    .char_indices()
+                    .step_by(stride.get())
+                    .map(|(offset, _)| {
+                        let offset = u32::try_from(offset)
+                            .expect("Strings longer than u32::MAX bytes can't be indexed");
+
+                        (key, offset)
+                    }),
+            );
+        }
+
+        // Safety: Every key was just created from an index into `strings` and every offset is a char boundary
+        suffixes.sort_unstable_by(|a, b| unsafe { suffix(strings, a).cmp(suffix(strings, b)) });
+        suffixes.shrink_to_fit();
+
+        Self { suffixes, stride }
+    }
+
+    /// Returns the number of characters between indexed positions
+    #[inline]
+    pub(crate) fn stride(&self) -> usize {
+        self.stride.get()
+    }
+
+    /// Returns the number of bytes used by the index
+    #[inline]
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        self.suffixes.capacity() * mem::size_of::<(K, u32)>()
+    }
+
+    /// Gets the keys of all strings containing `needle`, sorted and without duplicates. Returns `None` if the
+    /// needle is shorter than the stride, meaning the index can't be used to find it
+    ///
+    /// # Safety
+    ///
+    /// `strings` must be the same strings the index was created with
+    ///
+    #[inline]
+    pub(crate) unsafe fn containing(
+        &self,
+        strings: &StringTable<str>,
+        needle: &str,
+    ) -> Option<Vec<K>> {
+        // The index can only find needles with at least `stride` characters
+        needle.chars().nth(self.stride.get() - 1)?;
+
+        let mut boundaries = needle.char_indices().map(|(offset, _)| offset);
+        let mut keys = Vec::new();
+
+        for _ in 0..self.stride.get() {
+            // Each occurrence of the needle has an indexed position in its first `stride` characters,
+            // and `head` is the part of the needle that comes before that position
+            let split = boundaries.next()?;
+            let (head, tail) = needle.split_at(split);
+
+            let start = self
+                .suffixes
+                .partition_point(|entry| suffix(strings, entry) < tail);
+            let len = self.suffixes[start..]
+                .partition_point(|entry| suffix(strings, entry).starts_with(tail));
+
+            keys.extend(
+                self.suffixes[start..start + len]
+                    .iter()
+                    .filter(|(key, offset)| {
+                        strings.get_unchecked(key.into_usize())[..*offset as usize].ends_with(head)
+                    })
+                    .map(|&(key, _)| key),
+            );
+        }
+
+        keys.sort_unstable_by_key(|key| key.into_usize());
+        keys.dedup();
+
+        Some(keys)
+    }
+}
+
+/// Gets the suffix an index entry refers to
+///
+/// # Safety
+///
+/// The key must be an index into `strings` and the offset must be a char boundary of its string
+///
+#[inline]
+unsafe fn suffix<'a, K: Key>(strings: &'a StringTable<str>, &(key, offset): &(K, u32)) -> &'a str {
+    strings
+        .get_unchecked(key.into_usize())
+        .get_unchecked(offset as usize..)
+}
+
+/// Gets the longest run of literal characters in a glob pattern
+#[inline]
+pub(crate) fn longest_literal(pattern: &str) -> &str {
+    pattern
+        .split(['*', '?'])
+        .max_by_key(|literal| literal.chars().count())
+        .unwrap_or("")
+}
+
+/// Returns `true` if the whole string matches the glob pattern, where `*` matches any
+/// number of characters and `?` matches exactly one
+#[inline]
+pub(crate) fn glob_matches(pattern: &str, string: &str) -> bool {
+    let (pattern, string) = (pattern.as_bytes(), string.as_bytes());
+    let (mut p, mut s) = (0, 0);
+    // The position after the last `*` seen and the position in the string it's currently matched up to
+    let mut backtrack = None;
+
+    while s < string.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                p += 1;
+                backtrack = Some((p, s));
+            }
+            Some(b'?') => {
+                p += 1;
+                s += char_len(string[s]);
+            }
+            Some(&byte) if byte == string[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` swallow one more character and try again
+                Some((star_p, star_s)) => {
+                    let star_s = star_s + char_len(string[star_s]);
+
+                    p = star_p;
+                    s = star_s;
+                    backtrack = Some((star_p, star_s));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&byte| byte == b'*')
+}
+
+/// Gets the length of a UTF-8 encoded character from its first byte
+#[inline]
+fn char_len(first: u8) -> usize {
+    match first {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Spur;
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::vec;
+        }
+    }
+
+    fn containing(strings: &[&str], stride: usize, needle: &str) -> Option<Vec<usize>> {
+        let strings = StringTable::new(strings, None);
+        let index = SubstringIndex::<Spur>::new(&strings, NonZeroUsize::new(stride).unwrap());
+
+        unsafe { index.containing(&strings, needle) }.map(|keys| {
+            keys.into_iter()
+                .map(|key| unsafe { key.into_usize() })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn full_suffix_array() {
+        let strings = [
+            "RequestHandler",
+            "handle",
+            "Handler",
+            "ErrorHandlerTest",
+            "Hand",
+        ];
+
+        assert_eq!(Some(vec![0, 2, 3]), containing(&strings, 1, "Handler"));
+        assert_eq!(Some(vec![0, 1, 2, 3]), containing(&strings, 1, "andl"));
+        assert_eq!(Some(vec![3]), containing(&strings, 1, "rH"));
+        assert_eq!(Some(vec![0, 1, 2, 3, 4]), containing(&strings, 1, "and"));
+        assert_eq!(Some(vec![]), containing(&strings, 1, "Handlers"));
+        assert_eq!(None, containing(&strings, 1, ""));
+    }
+
+    #[test]
+    fn sparse() {
+        let strings = [
+            "RequestHandler",
+            "handle",
+            "Handler",
+            "ErrorHandlerTest",
+            "Hand",
+        ];
+
+        for stride in 2..=4 {
+            assert_eq!(Some(vec![0, 2, 3]), containing(&strings, stride, "Handler"));
+            assert_eq!(Some(vec![0, 1, 2, 3]), containing(&strings, stride, "andl"));
+            assert_eq!(Some(vec![]), containing(&strings, stride, "Handlers"));
+        }
+
+        assert_eq!(None, containing(&strings, 4, "and"));
+        assert_eq!(None, containing(&strings, 2, ""));
+    }
+
+    #[test]
+    fn repeated() {
+        let strings = ["aaaa", "aa", "a", "ba"];
+
+        assert_eq!(Some(vec![0, 1]), containing(&strings, 1, "aa"));
+        assert_eq!(Some(vec![0, 1]), containing(&strings, 2, "aa"));
+        assert_eq!(Some(vec![0]), containing(&strings, 3, "aaa"));
+        assert_eq!(Some(vec![3]), containing(&strings, 2, "ba"));
+    }
+
+    #[test]
+    fn multibyte() {
+        let strings = ["größe", "große", "grüße", "ß"];
+
+        assert_eq!(Some(vec![0, 1, 2, 3]), containing(&strings, 1, "ß"));
+        assert_eq!(Some(vec![0]), containing(&strings, 2, "öß"));
+        assert_eq!(Some(vec![2]), containing(&strings, 2, "üß"));
+        assert_eq!(Some(vec![1]), containing(&strings, 3, "roß"));
+    }
+
+    #[test]
+    fn globs() {
+        assert!(glob_matches("*_test", "parse_test"));
+        assert!(glob_matches("*_test", "_test"));
+        assert!(!glob_matches("*_test", "parse_tests"));
+        assert!(glob_matches("parse_*", "parse_"));
+        assert!(glob_matches("p?rse*", "parse_test"));
+        assert!(!glob_matches("p?rse", "prse"));
+        assert!(glob_matches("*Handler*", "ErrorHandlerTest"));
+        assert!(glob_matches("*a*b*c", "xxaxxbxxbxc"));
+        assert!(!glob_matches("*a*b*c", "xxaxxbxxbx"));
+        assert!(glob_matches("", ""));
+        assert!(!glob_matches("", "a"));
+        assert!(glob_matches("**", ""));
+        assert!(glob_matches("gr??e", "größe"));
+        assert!(glob_matches("*ö?e", "größe"));
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!("_test", longest_literal("*_test"));
+        assert_eq!("Handler", longest_literal("a*Handler?b"));
+        assert_eq!("", longest_literal("*?*"));
+        assert_eq!("größe", longest_literal("größe"));
+    }
+}
@@ -0,0 +1,603 @@
+use crate::internable::Internable;
+
+#[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+use crate::arena::Arena;
+
+use core::{convert::TryFrom, fmt, iter::FusedIterator, ops::Range};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::vec::Vec;
+    }
+}
+
+/// The strings of an interner indexed by their keys, which are either stored back to back in a single buffer
+/// where each string is found by its span within the buffer, or left in the arena they were interned into
+///
+/// Spans hold both ends of a string instead of every string ending where the next one starts, since a
+/// string whose suffixes are shared overlaps the strings stored inside of it
+pub(crate) struct StringTable<V: Internable + ?Sized> {
+    /// Where the strings are stored
+    storage: Storage<V>,
+    /// An item that's written directly after every string, if any
+    terminator: Option<Terminator<V::Raw>>,
+}
+
+/// How the strings of a [`StringTable`] are stored
+enum Storage<V: Internable + ?Sized> {
+    /// The contents of every string in a single buffer, each followed by the terminator if there is one,
+    /// along with where each string is within the buffer
+    Packed { buffer: Vec<V::Raw>, spans: Spans },
+    /// A reference to every string within the arena that holds them, which is how a [`ThreadedRodeo`]
+    /// stores its strings so that they can be taken over without copying them. Arenas are never terminated
+    ///
+    /// [`ThreadedRodeo`]: crate::ThreadedRodeo
+    #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+    Arena {
+        strings: Vec<&'static V>,
+        arena: Arena<V::Raw>,
+    },
+}
+
+/// An item that's written directly after every string of a table, along with the function that finds it
+/// within a string so that only terminated tables need `V::Raw: Eq`
+#[derive(Clone)]
+pub(crate) struct Terminator<T> {
+    item: T,
+    contained_in: fn(&[T], &T) -> bool,
+}
+
+impl<T> Terminator<T> {
+    /// Creates a terminator that's found within strings by comparing their items to it
+    #[inline]
+    pub(crate) fn new(item: T) -> Self
+    where
+        T: Eq,
+    {
+        Self {
+            item,
+            contained_in: |string, item| string.contains(item),
+        }
+    }
+
+    /// Returns `true` if the string contains the terminator, in which case it can't be stored
+    #[inline]
+    fn is_in(&self, string: &[T]) -> bool {
+        (self.contained_in)(string, &self.item)
+    }
+}
+
+/// The span of every string, which only takes 8 bytes per string unless the buffer outgrows `u32`
+#[derive(Debug, Clone)]
+enum Spans {
+    Narrow(Vec<[u32; 2]>),
+    Wide(Vec<[usize; 2]>),
+}
+
+impl<V> StringTable<V>
+where
+    V: Internable + ?Sized,
+{
+    /// Creates an empty table with room for `capacity` strings before it has to reallocate its spans
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize, terminator: Option<Terminator<V::Raw>>) -> Self {
+        Self {
+            storage: Storage::Packed {
+                buffer: Vec::new(),
+                spans: Spans::with_capacity(capacity, 0),
+            },
+            terminator,
+        }
+    }
+
+    /// Copies the given strings into a new table, where each string's index is its key
+    ///
+    /// The strings can't contain the terminator
+    ///
+    #[inline]
+    pub(crate) fn new(strings: &[&V], terminator: Option<Terminator<V::Raw>>) -> Self {
+        let terminated = terminator.is_some() as usize;
+        let size: usize = strings
+            .iter()
+            .map(|&string| string.to_raw().len() + terminated)
+            .sum();
+
+        let mut buffer: Vec<V::Raw> = Vec::with_capacity(size);
+        let mut spans = Spans::with_capacity(strings.len(), size);
+        for &string in strings {
+            let start = buffer.len();
+            buffer.extend_from_slice(string.to_raw());
+            spans.push(start..buffer.len());
+
+            buffer.extend(
+                terminator
+                    .as_ref()
+                    .map(|terminator| terminator.item.clone()),
+            );
+        }
+
+        Self {
+            storage: Storage::Packed { buffer, spans },
+            terminator,
+        }
+    }
+
+    /// Creates an unterminated table from strings that are kept where they are within the arena that
+    /// holds them, where each string's index is its key
+    ///
+    /// # Safety
+    ///
+    /// Every string must be stored within the arena
+    ///
+    #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+    #[inline]
+    pub(crate) unsafe fn from_arena(strings: Vec<&'static V>, arena: Arena<V::Raw>) -> Self {
+        Self {
+            storage: Storage::Arena { strings, arena },
+            terminator: None,
+        }
+    }
+
+    /// Adds a string after all the others, returning its index or `None` if it contains the terminator
+    #[inline]
+    pub(crate) fn push(&mut self, string: &V) -> Option<usize> {
+        let raw = string.to_raw();
+        let idx = self.len();
+
+        let terminator = match &self.terminator {
+            Some(terminator) if terminator.is_in(raw) => return None,
+            Some(terminator) => Some(terminator.item.clone()),
+            None => None,
+        };
+
+        match &mut self.storage {
+            Storage::Packed { buffer, spans } => {
+                let start = buffer.len();
+                buffer.extend_from_slice(raw);
+                spans.push(start..buffer.len());
+
+                buffer.extend(terminator);
+            }
+
+            // Safety: The raw slice was copied from a valid `V`, and the reference is dropped along with
+            // the arena that holds it
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { strings, arena } => {
+                strings.push(unsafe { V::from_raw(arena.store_slice(raw)) });
+            }
+        }
+
+        Some(idx)
+    }
+
+    /// Gets a string by its index, returning `None` if the index is out of bounds
+    #[inline]
+    pub(crate) fn get(&self, idx: usize) -> Option<&V> {
+        if idx < self.len() {
+            // Safety: The index was just bounds checked
+            Some(unsafe { self.get_unchecked(idx) })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a string by its index without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of strings
+    ///
+    #[inline]
+    pub(crate) unsafe fn get_unchecked(&self, idx: usize) -> &V {
+        match &self.storage {
+            // Safety: Every span is within the buffer and holds a copy of a valid `V`
+            Storage::Packed { buffer, spans } => {
+                V::from_raw(buffer.get_unchecked(spans.get_unchecked(idx)))
+            }
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { strings, .. } => strings.get_unchecked(idx),
+        }
+    }
+
+    /// Gets the number of strings
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Packed { spans, .. } => spans.len(),
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { strings, .. } => strings.len(),
+        }
+    }
+
+    /// Gets the number of strings the table can hold before it has to reallocate
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Packed { spans, .. } => spans.capacity(),
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { strings, .. } => strings.capacity(),
+        }
+    }
+
+    /// Returns `true` if a terminator is written after every string
+    #[inline]
+    pub(crate) fn is_terminated(&self) -> bool {
+        self.terminator.is_some()
+    }
+
+    /// Gets the terminator written after every string, if any
+    #[inline]
+    pub(crate) fn terminator(&self) -> Option<Terminator<V::Raw>> {
+        self.terminator.clone()
+    }
+
+    /// Returns an iterator over the strings in order of their indices
+    #[inline]
+    pub(crate) fn iter(&self) -> TableIter<'_, V> {
+        TableIter {
+            table: self,
+            indices: 0..self.len(),
+        }
+    }
+
+    /// Copies the strings into a new packed table like cloning, except that every string that's a suffix
+    /// of another one is stored as the tail of that string instead of on its own, also returning the number
+    /// of bytes that no longer have to be stored
+    #[inline]
+    pub(crate) fn share_suffixes(&self) -> (Self, usize)
+    where
+        V::Raw: Ord,
+    {
+        // Safety: The closure is only given indices less than the number of strings
+        let raw = |idx| unsafe { self.get_unchecked(idx) }.to_raw();
+
+        // Sorting by reversed contents from largest to smallest puts every string directly after the strings
+        // it's a suffix of, so each one only has to be checked against the last string that was stored
+        let mut order = (0..self.len()).collect::<Vec<usize>>();
+        order.sort_unstable_by(|&a, &b| raw(b).iter().rev().cmp(raw(a).iter().rev()));
+
+        let mut ranges = Vec::new();
+        ranges.resize(self.len(), 0..0);
+
+        let mut buffer = Vec::new();
+        let mut owner: Option<(&[V::Raw], usize)> = None;
+        let mut saved = 0;
+        for idx in order {
+            let string = raw(idx);
+
+            ranges[idx] = match owner {
+                Some((owner, end)) if owner.ends_with(string) => {
+                    saved += core::mem::size_of_val(string);
+                    end - string.len()..end
+                }
+
+                _ => {
+                    let start = buffer.len();
+                    buffer.extend_from_slice(string);
+                    owner = Some((string, buffer.len()));
+                    buffer.extend(
+                        self.terminator
+                            .as_ref()
+                            .map(|terminator| terminator.item.clone()),
+                    );
+
+                    start..start + string.len()
+                }
+            };
+        }
+        buffer.shrink_to_fit();
+
+        let mut spans = Spans::with_capacity(ranges.len(), buffer.len());
+        ranges.into_iter().for_each(|range| spans.push(range));
+
+        let table = Self {
+            storage: Storage::Packed { buffer, spans },
+            terminator: self.terminator.clone(),
+        };
+
+        (table, saved)
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl StringTable<str> {
+    /// Gets a string by its index as a `CStr`, including the terminator directly after it
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of strings and the table must be terminated by nul,
+    /// which means that its strings are packed
+    ///
+    #[inline]
+    pub(crate) unsafe fn get_cstr_unchecked(&self, idx: usize) -> &std::ffi::CStr {
+        match &self.storage {
+            Storage::Packed { buffer, spans } => {
+                let Range { start, end } = spans.get_unchecked(idx);
+
+                // Safety: A nul is written after every string and strings can't contain the terminator
+                std::ffi::CStr::from_bytes_with_nul_unchecked(buffer.get_unchecked(start..=end))
+            }
+
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { .. } => unreachable!("Strings within an arena aren't terminated"),
+        }
+    }
+}
+
+impl<V> Clone for StringTable<V>
+where
+    V: Internable + ?Sized,
+{
+    /// Strings that are still within an arena are packed into a single buffer instead of copying the arena
+    #[inline]
+    fn clone(&self) -> Self {
+        match &self.storage {
+            Storage::Packed { buffer, spans } => Self {
+                storage: Storage::Packed {
+                    buffer: buffer.clone(),
+                    spans: spans.clone(),
+                },
+                terminator: self.terminator.clone(),
+            },
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { strings, .. } => Self::new(strings, self.terminator.clone()),
+        }
+    }
+}
+
+impl<V> Default for StringTable<V>
+where
+    V: Internable + ?Sized,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_capacity(0, None)
+    }
+}
+
+impl<V> fmt::Debug for StringTable<V>
+where
+    V: Internable + fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Spans {
+    /// Creates spans for `len` strings that are within a buffer of `size` items
+    #[inline]
+    fn with_capacity(len: usize, size: usize) -> Self {
+        if u32::try_from(size).is_ok() {
+            Self::Narrow(Vec::with_capacity(len))
+        } else {
+            Self::Wide(Vec::with_capacity(len))
+        }
+    }
+
+    /// Adds the span of another string, widening every span once the buffer outgrows `u32`
+    #[inline]
+    fn push(&mut self, Range { start, end }: Range<usize>) {
+        match self {
+            Self::Narrow(spans) => {
+                if let Ok(narrow_end) = u32::try_from(end) {
+                    spans.push([start as u32, narrow_end]);
+                } else {
+                    let mut wide = Vec::with_capacity(spans.capacity().max(spans.len() + 1));
+                    wide.extend(
+                        spans
+                            .iter()
+                            .map(|&[start, end]| [start as usize, end as usize]),
+                    );
+                    wide.push([start, end]);
+
+                    *self = Self::Wide(wide);
+                }
+            }
+            Self::Wide(spans) => spans.push([start, end]),
+        }
+    }
+
+    /// Gets the number of spans
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::Narrow(spans) => spans.len(),
+            Self::Wide(spans) => spans.len(),
+        }
+    }
+
+    /// Gets the number of spans that fit without reallocating
+    #[inline]
+    fn capacity(&self) -> usize {
+        match self {
+            Self::Narrow(spans) => spans.capacity(),
+            Self::Wide(spans) => spans.capacity(),
+        }
+    }
+
+    /// Gets a span without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of spans
+    ///
+    #[inline]
+    unsafe fn get_unchecked(&self, idx: usize) -> Range<usize> {
+        match self {
+            Self::Narrow(spans) => {
+                let [start, end] = *spans.get_unchecked(idx);
+                start as usize..end as usize
+            }
+            Self::Wide(spans) => {
+                let [start, end] = *spans.get_unchecked(idx);
+                start..end
+            }
+        }
+    }
+}
+
+/// An iterator over the strings of a [`StringTable`]
+pub(crate) struct TableIter<'a, V: Internable + ?Sized> {
+    table: &'a StringTable<V>,
+    indices: Range<usize>,
+}
+
+impl<'a, V> Iterator for TableIter<'a, V>
+where
+    V: Internable + ?Sized,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = self.table;
+
+        // Safety: The indices are all less than the number of strings
+        self.indices
+            .next()
+            .map(|idx| unsafe { table.get_unchecked(idx) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a, V> ExactSizeIterator for TableIter<'a, V> where V: Internable + ?Sized {}
+
+impl<'a, V> FusedIterator for TableIter<'a, V> where V: Internable + ?Sized {}
+
+impl<'a, V> fmt::Debug for TableIter<'a, V>
+where
+    V: Internable + fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableIter")
+            .field("remaining", &self.indices.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::vec;
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let strings = ["a", "", "bcd", "ü"];
+        let table = StringTable::<str>::new(&strings, None);
+
+        assert_eq!(4, table.len());
+        assert!(!table.is_terminated());
+        assert_eq!(strings.to_vec(), table.iter().collect::<Vec<_>>());
+        assert_eq!(Some("bcd"), table.get(2));
+        assert_eq!(None, table.get(4));
+        assert_eq!(table.iter().len(), table.clone().iter().len());
+    }
+
+    #[test]
+    fn empty() {
+        let table = StringTable::<str>::new(&[], None);
+
+        assert_eq!(0, table.len());
+        assert_eq!(None, table.get(0));
+        assert_eq!(None, table.iter().next());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-std"))]
+    fn terminated() {
+        let table = StringTable::<str>::new(&["ab", "", "c"], Some(Terminator::new(0)));
+
+        assert!(table.is_terminated());
+        assert_eq!(vec!["ab", "", "c"], table.iter().collect::<Vec<_>>());
+        assert_eq!(
+            b"ab\0",
+            unsafe { table.get_cstr_unchecked(0) }.to_bytes_with_nul()
+        );
+        assert_eq!(
+            b"\0",
+            unsafe { table.get_cstr_unchecked(1) }.to_bytes_with_nul()
+        );
+    }
+
+    #[test]
+    fn shared_suffixes() {
+        let strings = ["c", "a.b.c", "b.c", "x", "", "a.b.c"];
+        let (table, saved) =
+            StringTable::<str>::new(&strings, Some(Terminator::new(0))).share_suffixes();
+
+        assert_eq!(strings.to_vec(), table.iter().collect::<Vec<_>>());
+        assert_eq!("c".len() + "b.c".len() + "a.b.c".len(), saved);
+        match table.storage {
+            Storage::Packed { buffer, .. } => assert_eq!("a.b.c\0x\0".len(), buffer.len()),
+            #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+            Storage::Arena { .. } => panic!("Shared suffixes are always packed"),
+        }
+    }
+
+    #[test]
+    fn push() {
+        let mut table = StringTable::<str>::with_capacity(2, Some(Terminator::new(0)));
+        assert_eq!(2, table.capacity());
+
+        assert_eq!(Some(0), table.push("ab"));
+        assert_eq!(Some(1), table.push(""));
+        assert_eq!(None, table.push("a\0b"));
+        assert_eq!(vec!["ab", ""], table.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+    fn arena() {
+        let mut arena = Arena::new();
+        let strings = ["ab", "", "c"]
+            .iter()
+            .map(|&string| unsafe { str::from_raw(arena.store_slice(string.to_raw())) })
+            .collect();
+        let mut table = unsafe { StringTable::<str>::from_arena(strings, arena) };
+
+        assert!(!table.is_terminated());
+        assert_eq!(Some(3), table.push("d"));
+        assert_eq!(vec!["ab", "", "c", "d"], table.iter().collect::<Vec<_>>());
+        assert_eq!(Some("c"), table.get(2));
+
+        // Cloning packs the strings
+        let clone = table.clone();
+        assert!(matches!(clone.storage, Storage::Packed { .. }));
+        assert_eq!(vec!["ab", "", "c", "d"], clone.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn widen_spans() {
+        let mut spans = Spans::with_capacity(2, 0);
+        spans.push(0..4);
+        spans.push(u32::MAX as usize..u32::MAX as usize + 2);
+
+        assert!(matches!(spans, Spans::Wide(_)));
+        assert_eq!(2, spans.len());
+        assert_eq!(0..4, unsafe { spans.get_unchecked(0) });
+        assert_eq!(u32::MAX as usize..u32::MAX as usize + 2, unsafe {
+            spans.get_unchecked(1)
+        });
+    }
+
+    #[test]
+    fn non_str() {
+        let strings: [&[u32]; 3] = [&[1, 2, 3], &[], &[2, 3]];
+        let table = StringTable::<[u32]>::new(&strings, None);
+        assert_eq!(strings.to_vec(), table.iter().collect::<Vec<_>>());
+
+        let (shared, saved) = table.share_suffixes();
+        assert_eq!(strings.to_vec(), shared.iter().collect::<Vec<_>>());
+        assert_eq!(2 * core::mem::size_of::<u32>(), saved);
+    }
+}
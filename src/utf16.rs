@@ -0,0 +1,588 @@
+use crate::internable::{AsInternable, Internable};
+
+use core::{
+    borrow::Borrow,
+    char, fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+compile! {
+    if #[feature = "no-std"] {
+        use alloc::{borrow::ToOwned, string::String, vec::Vec};
+    }
+}
+
+/// A validated, borrowed UTF-16 string
+///
+/// The UTF-16 counterpart to `str`, a `Utf16Str` is guaranteed to contain no unpaired surrogates,
+/// so it can always be converted into UTF-8 without loss. Interning it with a [`Rodeo`] stores
+/// the raw code units, and lookups can be made with any `AsRef<Utf16Str>` or with the UTF-8
+/// equivalent through [`Rodeo::get_utf8`], which encodes the string as it goes without allocating
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{Rodeo, Spur, Utf16Str, Utf16String};
+///
+/// let units: Vec<u16> = "Hello, world!".encode_utf16().collect();
+/// let string = Utf16Str::from_utf16(&units).unwrap();
+///
+/// let mut rodeo: Rodeo<Utf16Str, Spur> = Rodeo::new();
+/// let key = rodeo.get_or_intern(string);
+///
+/// // Lookups can be made from the UTF-8 equivalent
+/// assert_eq!(Some(key), rodeo.get_utf8("Hello, world!"));
+/// assert_eq!(Some(key), rodeo.get(Utf16String::from("Hello, world!")));
+/// assert_eq!("Hello, world!", rodeo.resolve(&key).to_string_lossy());
+/// ```
+///
+/// [`Rodeo`]: crate::Rodeo
+/// [`Rodeo::get_utf8`]: crate::Rodeo::get_utf8
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Utf16Str {
+    units: [u16],
+}
+
+impl Utf16Str {
+    /// Validates a slice of UTF-16 code units, returning an error if it contains any unpaired surrogates
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Utf16Str;
+    ///
+    /// let units: Vec<u16> = "Hello!".encode_utf16().collect();
+    /// assert!(Utf16Str::from_utf16(&units).is_ok());
+    ///
+    /// // An unpaired leading surrogate
+    /// assert!(Utf16Str::from_utf16(&[0x0048, 0xD800]).is_err());
+    /// ```
+    ///
+    #[inline]
+    pub fn from_utf16(units: &[u16]) -> Result<&Self, Utf16Error> {
+        let mut valid_up_to = 0;
+        for c in char::decode_utf16(units.iter().copied()) {
+            match c {
+                Ok(c) => valid_up_to += c.len_utf16(),
+                Err(_) => return Err(Utf16Error { valid_up_to }),
+            }
+        }
+
+        // Safety: All code units were just validated
+        Ok(unsafe { Self::from_utf16_unchecked(units) })
+    }
+
+    /// Converts a slice of UTF-16 code units without checking that it's valid
+    ///
+    /// # Safety
+    ///
+    /// The slice must not contain any unpaired surrogates
+    ///
+    #[inline]
+    pub unsafe fn from_utf16_unchecked(units: &[u16]) -> &Self {
+        // Safety: `Utf16Str` is a transparent wrapper around `[u16]`
+        &*(units as *const [u16] as *const Self)
+    }
+
+    /// Gets the raw UTF-16 code units of the string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Utf16String;
+    ///
+    /// let string = Utf16String::from("abc");
+    /// assert_eq!(&[0x61, 0x62, 0x63], string.as_slice());
+    /// ```
+    ///
+    #[inline]
+    pub fn as_slice(&self) -> &[u16] {
+        &self.units
+    }
+
+    /// Returns the length of the string in UTF-16 code units
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Returns `true` if the string contains no code units
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Returns an iterator over the `char`s of the string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Utf16String;
+    ///
+    /// let string = Utf16String::from("a𝄞");
+    /// assert_eq!(vec!['a', '𝄞'], string.chars().collect::<Vec<_>>());
+    /// ```
+    ///
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        char::decode_utf16(self.units.iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Converts the string into UTF-8
+    ///
+    /// Since a `Utf16Str` is always valid this never has to insert any `U+FFFD REPLACEMENT CHARACTER`s,
+    /// it exists for parity with `OsStr::to_string_lossy` and `CStr::to_string_lossy`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Utf16String;
+    ///
+    /// let string = Utf16String::from("Hello, world!");
+    /// assert_eq!("Hello, world!", string.to_string_lossy());
+    /// ```
+    ///
+    #[inline]
+    pub fn to_string_lossy(&self) -> String {
+        self.chars().collect()
+    }
+
+    /// Copies the string into an owned [`Utf16String`]
+    ///
+    /// [`Utf16String`]: crate::Utf16String
+    #[inline]
+    pub fn to_utf16_string(&self) -> Utf16String {
+        Utf16String {
+            units: self.units.to_vec(),
+        }
+    }
+}
+
+impl Internable for Utf16Str {
+    type Raw = u16;
+
+    #[inline]
+    fn to_raw(&self) -> &[Self::Raw] {
+        &self.units
+    }
+
+    #[inline]
+    unsafe fn from_raw(raw: &[Self::Raw]) -> &Self {
+        Self::from_utf16_unchecked(raw)
+    }
+}
+
+impl<T> AsInternable<Utf16Str> for T
+where
+    T: AsRef<Utf16Str> + ?Sized,
+{
+    #[inline]
+    fn as_internable(&self) -> &Utf16Str {
+        self.as_ref()
+    }
+}
+
+impl AsRef<Utf16Str> for Utf16Str {
+    #[inline]
+    fn as_ref(&self) -> &Utf16Str {
+        self
+    }
+}
+
+impl AsRef<[u16]> for Utf16Str {
+    #[inline]
+    fn as_ref(&self) -> &[u16] {
+        &self.units
+    }
+}
+
+/// Hashes each code unit on its own so that a `str` can be hashed the same way with [`EncodeUtf16`] while
+/// it's being encoded
+impl Hash for Utf16Str {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_units(self.units.iter().copied(), state);
+    }
+}
+
+/// A `str` that hashes and compares like the [`Utf16Str`] it encodes to, without encoding it into a buffer
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EncodeUtf16<'a>(pub(crate) &'a str);
+
+impl Hash for EncodeUtf16<'_> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_units(self.0.encode_utf16(), state);
+    }
+}
+
+/// Hashes code units one at a time, ending with a byte that no unit hashes to so that no string's
+/// hash is the prefix of another's
+#[inline]
+fn hash_units<H: Hasher>(units: impl Iterator<Item = u16>, state: &mut H) {
+    units.for_each(|unit| state.write_u16(unit));
+    state.write_u8(0xFF);
+}
+
+impl PartialEq<str> for Utf16Str {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.units.iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<Utf16Str> for str {
+    #[inline]
+    fn eq(&self, other: &Utf16Str) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&str> for Utf16Str {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl fmt::Display for Utf16Str {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        for c in self.chars() {
+            f.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Utf16Str {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_char('"')?;
+        for c in self.chars() {
+            for c in c.escape_debug() {
+                f.write_char(c)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl ToOwned for Utf16Str {
+    type Owned = Utf16String;
+
+    #[inline]
+    fn to_owned(&self) -> Self::Owned {
+        self.to_utf16_string()
+    }
+}
+
+impl From<&Utf16Str> for String {
+    #[inline]
+    fn from(string: &Utf16Str) -> Self {
+        string.to_string_lossy()
+    }
+}
+
+/// A validated, owned UTF-16 string
+///
+/// The owned counterpart of [`Utf16Str`], most often used to convert a `str` into UTF-16
+/// for lookups within an interner of [`Utf16Str`]s
+///
+/// # Example
+///
+/// ```rust
+/// use lasso::{Utf16Str, Utf16String};
+///
+/// let string = Utf16String::from("Hello, world!");
+/// let borrowed: &Utf16Str = &string;
+///
+/// assert_eq!(borrowed, "Hello, world!");
+/// ```
+///
+/// [`Utf16Str`]: crate::Utf16Str
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Utf16String {
+    units: Vec<u16>,
+}
+
+impl Utf16String {
+    /// Validates a vector of UTF-16 code units, returning an error if it contains any unpaired surrogates
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Utf16String;
+    ///
+    /// assert!(Utf16String::from_utf16(vec![0x0048, 0x0069]).is_ok());
+    /// assert!(Utf16String::from_utf16(vec![0x0048, 0xDC00]).is_err());
+    /// ```
+    ///
+    #[inline]
+    pub fn from_utf16(units: Vec<u16>) -> Result<Self, Utf16Error> {
+        Utf16Str::from_utf16(&units)?;
+
+        Ok(Self { units })
+    }
+
+    /// Converts a slice of UTF-16 code units, replacing any unpaired surrogates with
+    /// `U+FFFD REPLACEMENT CHARACTER`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lasso::Utf16String;
+    ///
+    /// let string = Utf16String::from_utf16_lossy(&[0x0048, 0xD800, 0x0069]);
+    /// assert_eq!(*string, "H\u{FFFD}i");
+    /// ```
+    ///
+    #[inline]
+    pub fn from_utf16_lossy(units: &[u16]) -> Self {
+        let mut string = Self::default();
+        for c in char::decode_utf16(units.iter().copied()) {
+            string.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+
+        string
+    }
+
+    /// Appends a `char` to the end of the string
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        let mut buf = [0; 2];
+        self.units.extend_from_slice(c.encode_utf16(&mut buf));
+    }
+
+    /// Appends a `str` to the end of the string
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.units.extend(string.encode_utf16());
+    }
+
+    /// Gets the string as a [`Utf16Str`]
+    ///
+    /// [`Utf16Str`]: crate::Utf16Str
+    #[inline]
+    pub fn as_utf16_str(&self) -> &Utf16Str {
+        // Safety: The contained code units are always valid
+        unsafe { Utf16Str::from_utf16_unchecked(&self.units) }
+    }
+
+    /// Consumes the string, returning the raw UTF-16 code units
+    #[inline]
+    pub fn into_vec(self) -> Vec<u16> {
+        self.units
+    }
+}
+
+impl Deref for Utf16String {
+    type Target = Utf16Str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_utf16_str()
+    }
+}
+
+impl AsRef<Utf16Str> for Utf16String {
+    #[inline]
+    fn as_ref(&self) -> &Utf16Str {
+        self.as_utf16_str()
+    }
+}
+
+impl AsRef<[u16]> for Utf16String {
+    #[inline]
+    fn as_ref(&self) -> &[u16] {
+        &self.units
+    }
+}
+
+impl Borrow<Utf16Str> for Utf16String {
+    #[inline]
+    fn borrow(&self) -> &Utf16Str {
+        self.as_utf16_str()
+    }
+}
+
+impl From<&str> for Utf16String {
+    #[inline]
+    fn from(string: &str) -> Self {
+        Self {
+            units: string.encode_utf16().collect(),
+        }
+    }
+}
+
+impl From<&Utf16Str> for Utf16String {
+    #[inline]
+    fn from(string: &Utf16Str) -> Self {
+        string.to_utf16_string()
+    }
+}
+
+impl From<Utf16String> for String {
+    #[inline]
+    fn from(string: Utf16String) -> Self {
+        string.to_string_lossy()
+    }
+}
+
+impl PartialEq<str> for Utf16String {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&str> for Utf16String {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl fmt::Display for Utf16String {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_utf16_str(), f)
+    }
+}
+
+impl fmt::Debug for Utf16String {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_utf16_str(), f)
+    }
+}
+
+/// The error returned when validating UTF-16 code units that contain an unpaired surrogate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Utf16Error {
+    valid_up_to: usize,
+}
+
+impl Utf16Error {
+    /// Returns the number of code units that were valid before the unpaired surrogate
+    #[inline]
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl fmt::Display for Utf16Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unpaired surrogate found in UTF-16 string after {} valid code units",
+            self.valid_up_to,
+        )
+    }
+}
+
+#[cfg(not(feature = "no-std"))]
+impl std::error::Error for Utf16Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    compile! {
+        if #[feature = "no-std"] {
+            use alloc::{format, string::ToString, vec};
+        }
+    }
+
+    fn units(string: &str) -> Vec<u16> {
+        string.encode_utf16().collect()
+    }
+
+    #[test]
+    fn from_utf16() {
+        let valid = units("Hello, 𝄞 world!");
+        let string = Utf16Str::from_utf16(&valid).unwrap();
+        assert_eq!(string.as_slice(), &valid[..]);
+        assert_eq!(string.len(), valid.len());
+        assert!(!string.is_empty());
+
+        let err = Utf16Str::from_utf16(&[0x0061, 0x0062, 0xDC00, 0x0063]).unwrap_err();
+        assert_eq!(err.valid_up_to(), 2);
+
+        let err = Utf16Str::from_utf16(&[0x0061, 0xD834]).unwrap_err();
+        assert_eq!(err.valid_up_to(), 1);
+
+        assert!(Utf16Str::from_utf16(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn str_conversions() {
+        let string = Utf16String::from("Hello, 𝄞 world!");
+        assert_eq!(string.as_slice(), &units("Hello, 𝄞 world!")[..]);
+        assert_eq!(string.to_string_lossy(), "Hello, 𝄞 world!");
+        assert_eq!(string.to_string(), "Hello, 𝄞 world!");
+        assert_eq!(String::from(string.clone()), "Hello, 𝄞 world!");
+        assert_eq!(string, "Hello, 𝄞 world!");
+        assert!(*string != *"Hello, world!");
+    }
+
+    #[test]
+    fn lossy() {
+        let string = Utf16String::from_utf16_lossy(&[0x0061, 0xD834, 0x0062, 0xDC00]);
+        assert_eq!(string, "a\u{FFFD}b\u{FFFD}");
+
+        assert!(Utf16String::from_utf16(vec![0x0061, 0xD834]).is_err());
+        assert_eq!(
+            Utf16String::from_utf16(units("valid")).unwrap(),
+            Utf16String::from("valid"),
+        );
+    }
+
+    #[test]
+    fn push() {
+        let mut string = Utf16String::default();
+        string.push('a');
+        string.push('𝄞');
+        string.push_str("bc");
+
+        assert_eq!(string, "a𝄞bc");
+        assert_eq!(string.into_vec(), units("a𝄞bc"));
+    }
+
+    #[test]
+    fn hash_without_encoding() {
+        use crate::hasher::RandomState;
+        use core::hash::BuildHasher;
+
+        let state = RandomState::new();
+        for &string in &["", "a", "Hello, 𝄞 world!", "\u{FFFF}"] {
+            let encoded = Utf16String::from(string);
+
+            assert_eq!(
+                state.hash_one(encoded.as_utf16_str()),
+                state.hash_one(EncodeUtf16(string)),
+            );
+            assert_eq!(*encoded, *string);
+        }
+
+        assert_ne!(
+            state.hash_one(EncodeUtf16("a")),
+            state.hash_one(EncodeUtf16("ab"))
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let string = Utf16String::from("a\"b\n");
+        assert_eq!(format!("{:?}", string), format!("{:?}", "a\"b\n"));
+    }
+}
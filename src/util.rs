@@ -1,9 +1,18 @@
 use crate::{
-    internable::Internable, key::Key, reader::RodeoReader, resolver::RodeoResolver,
+    internable::Internable,
+    key::Key,
+    normalizer::{Identity, Normalized, Normalizer},
+    reader::RodeoReader,
+    resolver::RodeoResolver,
     single_threaded::Rodeo,
+    sorted::SortedReader,
+    table::TableIter,
 };
 
-use core::{hash::BuildHasher, iter, marker::PhantomData, slice};
+use core::{any::TypeId, hash::BuildHasher, iter, marker::PhantomData};
+
+#[cfg(feature = "no-std")]
+use alloc::vec::Vec;
 
 #[derive(Debug)]
 pub struct Iter<'a, V, K>
@@ -11,7 +20,7 @@ where
     V: Internable + ?Sized,
     K: Key,
 {
-    iter: iter::Enumerate<slice::Iter<'a, &'a V>>,
+    iter: iter::Enumerate<TableIter<'a, V>>,
     __key: PhantomData<K>,
 }
 
@@ -21,20 +30,25 @@ where
     K: Key,
 {
     #[inline]
-    pub(crate) fn from_rodeo<H>(rodeo: &'a Rodeo<V, K, H>) -> Self
+    pub(crate) fn from_rodeo<H, N>(rodeo: &'a Rodeo<V, K, H, N>) -> Self
     where
         H: BuildHasher + Clone,
+        N: Normalizer<V>,
     {
         Self {
-            iter: rodeo.strings.iter().enumerate(),
+            iter: rodeo.table.iter().enumerate(),
             __key: PhantomData,
         }
     }
 
     #[inline]
-    pub(crate) fn from_reader<H: BuildHasher + Clone>(rodeo: &'a RodeoReader<V, K, H>) -> Self {
+    pub(crate) fn from_reader<H, N>(rodeo: &'a RodeoReader<V, K, H, N>) -> Self
+    where
+        H: BuildHasher + Clone,
+        N: Normalizer<V>,
+    {
         Self {
-            iter: rodeo.strings.iter().enumerate(),
+            iter: rodeo.table.iter().enumerate(),
             __key: PhantomData,
         }
     }
@@ -42,7 +56,15 @@ where
     #[inline]
     pub(crate) fn from_resolver(rodeo: &'a RodeoResolver<V, K>) -> Self {
         Self {
-            iter: rodeo.strings.iter().enumerate(),
+            iter: rodeo.table.iter().enumerate(),
+            __key: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_sorted(rodeo: &'a SortedReader<V, K>) -> Self {
+        Self {
+            iter: rodeo.table.iter().enumerate(),
             __key: PhantomData,
         }
     }
@@ -60,7 +82,7 @@ where
         self.iter.next().map(|(key, string)| {
             (
                 K::try_from_usize(key).unwrap_or_else(|| unreachable!()),
-                *string,
+                string,
             )
         })
     }
@@ -99,7 +121,7 @@ where
     V: Internable + ?Sized,
     K: Key,
 {
-    iter: slice::Iter<'a, &'a V>,
+    iter: TableIter<'a, V>,
     __key: PhantomData<K>,
 }
 
@@ -109,20 +131,25 @@ where
     K: Key,
 {
     #[inline]
-    pub(crate) fn from_rodeo<H: BuildHasher + Clone>(rodeo: &'a Rodeo<V, K, H>) -> Self
+    pub(crate) fn from_rodeo<H, N>(rodeo: &'a Rodeo<V, K, H, N>) -> Self
     where
         H: BuildHasher + Clone,
+        N: Normalizer<V>,
     {
         Self {
-            iter: rodeo.strings.iter(),
+            iter: rodeo.table.iter(),
             __key: PhantomData,
         }
     }
 
     #[inline]
-    pub(crate) fn from_reader<H: BuildHasher + Clone>(rodeo: &'a RodeoReader<V, K, H>) -> Self {
+    pub(crate) fn from_reader<H, N>(rodeo: &'a RodeoReader<V, K, H, N>) -> Self
+    where
+        H: BuildHasher + Clone,
+        N: Normalizer<V>,
+    {
         Self {
-            iter: rodeo.strings.iter(),
+            iter: rodeo.table.iter(),
             __key: PhantomData,
         }
     }
@@ -130,7 +157,15 @@ where
     #[inline]
     pub(crate) fn from_resolver(rodeo: &'a RodeoResolver<V, K>) -> Self {
         Self {
-            iter: rodeo.strings.iter(),
+            iter: rodeo.table.iter(),
+            __key: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_sorted(rodeo: &'a SortedReader<V, K>) -> Self {
+        Self {
+            iter: rodeo.table.iter(),
             __key: PhantomData,
         }
     }
@@ -145,7 +180,7 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().copied()
+        self.iter.next()
     }
 
     #[inline]
@@ -226,71 +261,86 @@ macro_rules! compile {
 
     (@inner ($($prev_metas:tt)*))=>{};
 }
-macro_rules! compile_expr {
-    ($(
-        if #[$meta:meta] {
-            $( $stmt:tt )*
-        } $(else if #[$else_if_meta:meta] {
-            $( $else_if_stmt:tt )*
-        })* $(else {
-            $( $else_stmt:tt )*
-        })?
-    )+) => {
-        $(
-            #[cfg($meta)]
-            {
-                $( $stmt )*
-            }
+/// Gets the index of every one of `len` strings in sorted order, keeping equal strings in the order
+/// they were given
+#[inline]
+pub(crate) fn sorted_order<'a, V, F>(len: usize, string: F) -> Vec<usize>
+where
+    V: Ord + ?Sized + 'a,
+    F: Fn(usize) -> &'a V,
+{
+    let mut order = (0..len).collect::<Vec<usize>>();
+    order.sort_by(|&a, &b| string(a).cmp(string(b)));
 
-            compile_expr! {
-                @inner
-                ( $meta, )
-                $(else if #[$else_if_meta] {
-                    $( $else_if_stmt )*
-                })* $(else {
-                    $( $else_stmt )*
-                })?
-            }
-        )+
-    };
+    order
+}
 
-    (@recurse ($( $prev_metas:tt)* ) ($new_meta:meta) $( $rem:tt )*) => {
-        compile_expr! {
-            @inner
-            ($( $prev_metas )* $new_meta,)
-            $( $rem )*
-        }
-    };
+/// A map holding only the keys of an interner, where each key is found by its string's hash and
+/// compared through the interner's own strings instead of through a reference to its string stored
+/// in the map, which halves the size of every entry
+pub(crate) type KeyMap<K, S> = hashbrown::HashMap<K, (), S>;
 
-    (@inner
-        $prev_metas:tt
-        else if #[$meta:meta] {
-            $( $else_if_stmt:tt )*
-        }
-        $( $rem:tt )*
-    ) => {
-        #[cfg(all(not(any $prev_metas), $meta))]
-        {
-            $( $else_if_stmt )*
-        }
+/// Creates a [`KeyMap`] holding the key of every hash, where each hash's index is its key
+#[inline]
+#[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+pub(crate) fn key_map<K, S>(hashes: &[u64], hash_builder: S) -> KeyMap<K, S>
+where
+    K: Key,
+{
+    use hashbrown::hash_map::RawEntryMut;
 
-        compile_expr! {
-            @recurse $prev_metas ($meta) $( $rem )*
-        }
-    };
+    let mut map = KeyMap::with_capacity_and_hasher(hashes.len(), hash_builder);
 
-    (@inner $prev_metas:tt
-        else {
-            $( $else_stmt:tt )*
-        }
-    ) => {
-        #[cfg(not(any $prev_metas))]
-        {
-            $( $else_stmt )*
+    for (idx, &hash) in hashes.iter().enumerate() {
+        let key = K::try_from_usize(idx).unwrap_or_else(|| unreachable!());
+
+        // Every key is unique, so none of them match a key that's already in the map
+        if let RawEntryMut::Vacant(entry) = map.raw_entry_mut().from_hash(hash, |_| false) {
+            entry.insert_with_hasher(hash, key, (), |key| hash_of(hashes, key));
         }
-    };
+    }
+
+    map
+}
+
+/// Finds the key of a string by its hash, where `is_match` compares the string of a key's index
+/// to the string being looked up
+#[inline]
+pub(crate) fn find_key<K, S, F>(map: &KeyMap<K, S>, hash: u64, mut is_match: F) -> Option<K>
+where
+    K: Key,
+    F: FnMut(usize) -> bool,
+{
+    map.raw_entry()
+        // Safety: The index is only given to `is_match`, which bounds checks it or gets it from a key in the map
+        .from_hash(hash, |key| is_match(unsafe { key.into_usize() }))
+        .map(|(&key, _)| key)
+}
+
+/// Creates a key that holds the string within itself if the key can, see [`Key::try_inline`]
+///
+/// Only interners that compare strings exactly hold strings within keys, since equal strings with
+/// different spellings would get different keys, and only ones that aren't terminated so that every
+/// key they give out can be resolved as a `CStr`
+#[inline]
+pub(crate) fn inline_key<V, K, N>(val: &Normalized<V, N>, terminated: bool) -> Option<K>
+where
+    V: Internable + ?Sized,
+    K: Key,
+    N: Normalizer<V>,
+{
+    if terminated || TypeId::of::<N>() != TypeId::of::<Identity>() {
+        return None;
+    }
+
+    K::try_inline(val.value())
+}
 
-    (@inner ($($prev_metas:tt)*)) => {};
+/// Gets the cached hash of a key's string, used to move keys when a [`KeyMap`] grows
+#[inline]
+pub(crate) fn hash_of<K: Key>(hashes: &[u64], key: &K) -> u64 {
+    // Safety: Every key in a map has a cached hash
+    unsafe { *hashes.get_unchecked(key.into_usize()) }
 }
 
 #[cfg(test)]
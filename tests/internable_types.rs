@@ -64,3 +64,194 @@ fn cstr() {
         CStr::from_bytes_with_nul(b"some random strings I've got\0").unwrap(),
     );
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Signature {
+    name: Spur,
+    params: Vec<Spur>,
+    ret: Option<Spur>,
+}
+
+#[test]
+fn tuples() {
+    let mut strings: Rodeo<str, Spur> = Rodeo::new();
+    let a = strings.get_or_intern("a");
+    let b = strings.get_or_intern("b");
+
+    let mut rodeo: Rodeo<(Spur, Spur), Spur> = Rodeo::new();
+
+    let pair = &(a, b);
+    let key1 = rodeo.get_or_intern((a, b));
+    let key2 = rodeo.get_or_intern(pair);
+    let key3 = rodeo.get_or_intern((b, a));
+
+    assert_eq!(key1, key2);
+    assert_ne!(key1, key3);
+    assert_eq!(rodeo.resolve(&key1), &(a, b));
+    assert_eq!(rodeo.resolve(&key3), &(b, a));
+    assert_eq!(rodeo.get((a, b)), Some(key1));
+    assert_eq!(rodeo.get((a, a)), None);
+}
+
+#[test]
+fn structs() {
+    let mut strings: Rodeo<str, Spur> = Rodeo::new();
+    let signature = Signature {
+        name: strings.get_or_intern("main"),
+        params: vec![strings.get_or_intern("argc"), strings.get_or_intern("argv")],
+        ret: None,
+    };
+
+    let mut rodeo: Rodeo<Signature, Spur> = Rodeo::new();
+
+    let key1 = rodeo.get_or_intern(&signature);
+    let key2 = rodeo.get_or_intern(signature.clone());
+
+    assert_eq!(key1, key2);
+    assert_eq!(rodeo.len(), 1);
+    assert_eq!(rodeo.resolve(&key1), &signature);
+
+    let reader = rodeo.into_reader();
+    assert_eq!(reader.get(&signature), Some(key1));
+    assert_eq!(reader.resolve(&key1), &signature);
+
+    let resolver = reader.into_resolver();
+    assert_eq!(resolver.resolve(&key1), &signature);
+}
+
+#[test]
+fn zsts() {
+    let mut rodeo: Rodeo<(), Spur> = Rodeo::new();
+
+    let key1 = rodeo.get_or_intern(());
+    let key2 = rodeo.get_or_intern(());
+
+    assert_eq!(key1, key2);
+    assert_eq!(rodeo.resolve(&key1), &());
+}
+
+#[test]
+#[cfg(all(feature = "multi-threaded", not(feature = "no-std")))]
+fn threaded_structs() {
+    use lasso::ThreadedRodeo;
+
+    let strings: ThreadedRodeo<str, Spur> = ThreadedRodeo::new();
+    let signature = Signature {
+        name: strings.get_or_intern("main"),
+        params: Vec::new(),
+        ret: Some(strings.get_or_intern("i32")),
+    };
+
+    let rodeo: ThreadedRodeo<Signature, Spur> = ThreadedRodeo::new();
+
+    let key1 = rodeo.get_or_intern(&signature);
+    let key2 = rodeo.get_or_intern(signature.clone());
+
+    assert_eq!(key1, key2);
+    assert_eq!(rodeo.resolve(&key1), &signature);
+    assert_eq!(rodeo.get(&signature), Some(key1));
+}
+
+#[test]
+#[cfg(not(feature = "no-std"))]
+fn os_str() {
+    use std::ffi::{OsStr, OsString};
+
+    let string = OsString::from("some random strings I've got");
+    let mut rodeo: Rodeo<OsStr, Spur> = Rodeo::new();
+
+    let key1 = rodeo.get_or_intern(&string);
+    let key2 = rodeo.get_or_intern(string.clone());
+    let key3 = rodeo.get_or_intern("some random strings I've got");
+
+    assert_eq!(key1, key2);
+    assert_eq!(key1, key3);
+    assert_eq!(
+        rodeo.resolve(&key1),
+        OsStr::new("some random strings I've got")
+    );
+    assert_eq!(rodeo.get(string), Some(key1));
+}
+
+#[test]
+#[cfg(not(feature = "no-std"))]
+fn paths() {
+    use std::path::{Path, PathBuf};
+
+    let path = PathBuf::from("src/internable.rs");
+    let mut rodeo: Rodeo<Path, Spur> = Rodeo::new();
+
+    let key1 = rodeo.get_or_intern(&path);
+    let key2 = rodeo.get_or_intern(path.as_path());
+    let key3 = rodeo.get_or_intern("src/internable.rs");
+    let other = rodeo.get_or_intern("src/lib.rs");
+
+    assert_eq!(key1, key2);
+    assert_eq!(key1, key3);
+    assert_ne!(key1, other);
+    assert_eq!(rodeo.resolve(&key1), Path::new("src/internable.rs"));
+    assert_eq!(rodeo.resolve(&key1).extension(), Some("rs".as_ref()));
+
+    let reader = rodeo.into_reader();
+    assert_eq!(reader.get(&path), Some(key1));
+    assert_eq!(reader.resolve(&other), Path::new("src/lib.rs"));
+}
+
+#[test]
+#[cfg(all(unix, not(feature = "no-std")))]
+fn non_utf8_paths() {
+    use std::{
+        ffi::OsStr,
+        os::unix::ffi::OsStrExt,
+        path::{Path, PathBuf},
+    };
+
+    let invalid = OsStr::from_bytes(b"build/\xFF\xFEobj.o");
+    let lossy = PathBuf::from(invalid.to_string_lossy().into_owned());
+    assert!(invalid.to_str().is_none());
+
+    let mut rodeo: Rodeo<Path, Spur> = Rodeo::new();
+
+    let key1 = rodeo.get_or_intern(Path::new(invalid));
+    let key2 = rodeo.get_or_intern(PathBuf::from(invalid));
+    let key3 = rodeo.get_or_intern(&lossy);
+
+    assert_eq!(key1, key2);
+    assert_ne!(key1, key3);
+    assert_eq!(
+        rodeo.resolve(&key1).as_os_str().as_bytes(),
+        b"build/\xFF\xFEobj.o"
+    );
+    assert_eq!(rodeo.resolve(&key3), lossy.as_path());
+}
+
+#[test]
+fn utf16() {
+    use lasso::{Utf16Str, Utf16String};
+
+    let units: Vec<u16> = "some random strings I've got 𝄞".encode_utf16().collect();
+    let string = Utf16Str::from_utf16(&units).unwrap();
+    let mut rodeo: Rodeo<Utf16Str, Spur> = Rodeo::new();
+
+    let key1 = rodeo.get_or_intern(string);
+    let key2 = rodeo.get_or_intern(Utf16String::from("some random strings I've got 𝄞"));
+    let other = rodeo.get_or_intern(Utf16String::from("something else"));
+
+    assert_eq!(key1, key2);
+    assert_ne!(key1, other);
+    assert_eq!(rodeo.resolve(&key1), "some random strings I've got 𝄞");
+    assert_eq!(rodeo.resolve(&key1).as_slice(), &units[..]);
+    assert_eq!(
+        rodeo.resolve(&key1).to_string_lossy(),
+        "some random strings I've got 𝄞",
+    );
+
+    // Lookups by the UTF-8 equivalent
+    let reader = rodeo.into_reader();
+    assert_eq!(
+        reader.get(Utf16String::from("some random strings I've got 𝄞")),
+        Some(key1),
+    );
+    assert_eq!(reader.get(Utf16String::from("missing")), None);
+    assert_eq!(reader.resolve(&other).to_string(), "something else");
+}